@@ -0,0 +1,38 @@
+//! Compares `SmallRingElement` multiplication with hardware `%` against Montgomery
+//! multiplication on exponentiation-heavy workloads.
+//!
+//! Run with `cargo bench --bench montgomery`.
+
+use std::{hint::black_box, time::Instant};
+
+use zk_exam::{
+    montgomery::MontgomeryRing,
+    ring::{Ring, RingElement, SmallRing},
+};
+
+/// Largest prime below 2^32
+const MODULE: u32 = 4294967291;
+const EXPONENTIATIONS: u64 = 20_000;
+
+fn bench<E: RingElement>(name: &str, create: impl Fn(u64) -> E) {
+    let one = create(1);
+    let start = Instant::now();
+    let mut acc = one;
+    for i in 0..EXPONENTIATIONS {
+        let base = create(i + 2);
+        acc = acc + black_box(base).pow(black_box(MODULE as u64 - 2));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<12} {EXPONENTIATIONS} exponentiations: {elapsed:?} ({:?} per pow), checksum {}",
+        elapsed / EXPONENTIATIONS as u32,
+        black_box(acc)
+    );
+}
+
+fn main() {
+    let small = SmallRing::new(MODULE).unwrap();
+    let montgomery = MontgomeryRing::new(MODULE as u64).unwrap();
+    bench("SmallRing", |value| small.create_element(value));
+    bench("Montgomery", |value| montgomery.create_element(value));
+}
@@ -0,0 +1,492 @@
+use std::{
+    cmp,
+    hash::Hash,
+    ops::{Add, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use crate::ring::{Exponent, Ring, RingElement, RingError};
+
+/// Fixed width unsigned integer of `LIMBS` 64-bit limbs, least significant limb first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+pub type U256 = Uint<4>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseUintError {
+    Empty,
+    InvalidDigit,
+    Overflow,
+}
+
+impl std::fmt::Display for ParseUintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseUintError::Empty => f.write_str("Cannot parse integer from empty string"),
+            ParseUintError::InvalidDigit => f.write_str("Invalid digit found in string"),
+            ParseUintError::Overflow => f.write_str("Number too large to fit in target type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseUintError {}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+    pub const BITS: usize = LIMBS * 64;
+
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    pub fn limbs(&self) -> &[u64; LIMBS] {
+        &self.limbs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|limb| *limb == 0)
+    }
+
+    pub fn bit(&self, i: usize) -> bool {
+        (self.limbs[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// Number of significant bits
+    pub fn bits(&self) -> usize {
+        for i in (0..LIMBS).rev() {
+            if self.limbs[i] != 0 {
+                return i * 64 + 64 - self.limbs[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    /// Value as `u64` if it fits
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.limbs.iter().skip(1).any(|limb| *limb != 0) {
+            return None;
+        }
+        Some(self.limbs.first().copied().unwrap_or(0))
+    }
+
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (Self { limbs }, carry)
+    }
+
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        (Self { limbs }, borrow)
+    }
+
+    /// Shifts left by one bit, returns the shifted out bit
+    pub fn overflowing_shl1(&self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = 0;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = (self.limbs[i] << 1) | carry;
+            carry = self.limbs[i] >> 63;
+        }
+        (Self { limbs }, carry == 1)
+    }
+
+    pub fn shr1(&self) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = self.limbs[i] >> 1;
+            if i + 1 < LIMBS {
+                *limb |= self.limbs[i + 1] << 63;
+            }
+        }
+        Self { limbs }
+    }
+
+    /// Schoolbook binary long division, panics on zero divisor
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if divisor.is_zero() {
+            panic!("Division by zero");
+        }
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for i in (0..self.bits()).rev() {
+            let (shifted, overflow) = remainder.overflowing_shl1();
+            remainder = shifted;
+            remainder.limbs[0] |= self.bit(i) as u64;
+            if overflow || remainder >= *divisor {
+                remainder = remainder.overflowing_sub(divisor).0;
+                quotient.limbs[i / 64] |= 1 << (i % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    pub fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        if divisor == 0 {
+            panic!("Division by zero");
+        }
+        let mut limbs = [0; LIMBS];
+        let mut remainder: u128 = 0;
+        for i in (0..LIMBS).rev() {
+            let current = (remainder << 64) | self.limbs[i] as u128;
+            limbs[i] = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+        (Self { limbs }, remainder as u64)
+    }
+
+    /// `self * factor + addend`, `None` on overflow
+    fn checked_mul_add_u64(&self, factor: u64, addend: u64) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        let mut carry = addend as u128;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let current = self.limbs[i] as u128 * factor as u128 + carry;
+            *limb = current as u64;
+            carry = current >> 64;
+        }
+        (carry == 0).then_some(Self { limbs })
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseUintError> {
+        if s.is_empty() {
+            return Err(ParseUintError::Empty);
+        }
+        let mut result = Self::ZERO;
+        for c in s.chars().filter(|c| *c != '_') {
+            let digit = c.to_digit(radix).ok_or(ParseUintError::InvalidDigit)?;
+            result = result
+                .checked_mul_add_u64(radix as u64, digit as u64)
+                .ok_or(ParseUintError::Overflow)?;
+        }
+        Ok(result)
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for Uint<LIMBS> {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value;
+        Self { limbs }
+    }
+}
+
+/// Parses decimal or `0x`-prefixed hexadecimal strings
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = ParseUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x") {
+            Some(hex) => Self::from_str_radix(hex, 16),
+            None => Self::from_str_radix(s, 10),
+        }
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Display for Uint<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Chunks of 19 decimal digits, least significant first
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = vec![];
+        let mut rest = *self;
+        loop {
+            let (quotient, chunk) = rest.div_rem_u64(CHUNK);
+            chunks.push(chunk);
+            if quotient.is_zero() {
+                break;
+            }
+            rest = quotient;
+        }
+        let mut digits = chunks.pop().expect("Infallible").to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::LowerHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut digits = String::new();
+        for limb in self.limbs.iter().rev() {
+            if digits.is_empty() {
+                if *limb != 0 {
+                    digits = format!("{limb:x}");
+                }
+            } else {
+                digits.push_str(&format!("{limb:016x}"));
+            }
+        }
+        if digits.is_empty() {
+            digits.push('0');
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigRing<const LIMBS: usize> {
+    module: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> BigRing<LIMBS> {
+    pub fn new(module: Uint<LIMBS>) -> Result<Self, RingError> {
+        if module.bits() < 2 {
+            return Err(RingError::InvalidModule(module.limbs[0] as u32));
+        }
+        Ok(Self { module })
+    }
+
+    fn add_mod(&self, x: &Uint<LIMBS>, y: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let (sum, overflow) = x.overflowing_add(y);
+        if overflow || sum >= self.module {
+            sum.overflowing_sub(&self.module).0
+        } else {
+            sum
+        }
+    }
+
+    fn sub_mod(&self, x: &Uint<LIMBS>, y: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let (diff, borrow) = x.overflowing_sub(y);
+        if borrow {
+            diff.overflowing_add(&self.module).0
+        } else {
+            diff
+        }
+    }
+
+    /// Double-and-add over the bits of `y`, so no double width product is needed
+    fn mul_mod(&self, x: &Uint<LIMBS>, y: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let mut result = Uint::ZERO;
+        for i in (0..y.bits()).rev() {
+            result = self.add_mod(&result, &result);
+            if y.bit(i) {
+                result = self.add_mod(&result, x);
+            }
+        }
+        result
+    }
+}
+
+impl BigRing<4> {
+    /// Scalar field of BN254
+    pub fn bn254_scalar() -> Self {
+        Self {
+            module: Uint::from_limbs([
+                0x43e1f593f0000001,
+                0x2833e84879b97091,
+                0xb85045b68181585d,
+                0x30644e72e131a029,
+            ]),
+        }
+    }
+
+    /// Scalar field of BLS12-381
+    pub fn bls12_381_scalar() -> Self {
+        Self {
+            module: Uint::from_limbs([
+                0xffffffff00000001,
+                0x53bda402fffe5bfe,
+                0x3339d80809a1d805,
+                0x73eda753299d7d48,
+            ]),
+        }
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Display for BigRing<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Ring (mod ")?;
+        self.module.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<const LIMBS: usize> Ring for BigRing<LIMBS> {
+    type Element = BigRingElement<LIMBS>;
+    type Module = Uint<LIMBS>;
+    type Value = Uint<LIMBS>;
+
+    /// Creates an element reducing `value` by the ring module
+    fn create_element(&self, value: Self::Value) -> Self::Element {
+        BigRingElement {
+            ring: *self,
+            value: value.div_rem(&self.module).1,
+        }
+    }
+
+    fn module(&self) -> &Self::Module {
+        &self.module
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.create_element(Uint::ZERO)
+    }
+
+    fn one(&self) -> Self::Element {
+        self.create_element(Uint::from(1))
+    }
+
+    fn cardinality(&self) -> Option<u64> {
+        self.module.to_u64()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigRingElement<const LIMBS: usize> {
+    ring: BigRing<LIMBS>,
+    value: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> BigRingElement<LIMBS> {
+    pub fn value(&self) -> &Uint<LIMBS> {
+        &self.value
+    }
+
+    fn check_ring(&self, rhs: &Self) {
+        if self.ring != rhs.ring {
+            panic!(
+                "Ring operation failed, lhs ring: {}, rhs ring: {}",
+                self.ring, rhs.ring
+            );
+        }
+    }
+}
+
+impl<const LIMBS: usize> Add for BigRingElement<LIMBS> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        Self {
+            ring: self.ring,
+            value: self.ring.add_mod(&self.value, &rhs.value),
+        }
+    }
+}
+
+impl<const LIMBS: usize> Sub for BigRingElement<LIMBS> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        Self {
+            ring: self.ring,
+            value: self.ring.sub_mod(&self.value, &rhs.value),
+        }
+    }
+}
+
+impl<const LIMBS: usize> Mul for BigRingElement<LIMBS> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        Self {
+            ring: self.ring,
+            value: self.ring.mul_mod(&self.value, &rhs.value),
+        }
+    }
+}
+
+impl<const LIMBS: usize> Rem for BigRingElement<LIMBS> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        if rhs.value.is_zero() {
+            panic!("Ring operation failed: {}", RingError::DivisionByZero);
+        }
+        let value = self.value.div_rem(&rhs.value).1;
+        self.ring.create_element(value)
+    }
+}
+
+impl<const LIMBS: usize> Neg for BigRingElement<LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            ring: self.ring,
+            value: self.ring.sub_mod(&Uint::ZERO, &self.value),
+        }
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for BigRingElement<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for BigRingElement<LIMBS> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Display for BigRingElement<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<const LIMBS: usize> RingElement for BigRingElement<LIMBS> {
+    type Ring = BigRing<LIMBS>;
+
+    fn ring(&self) -> &Self::Ring {
+        &self.ring
+    }
+
+    /// Extended Euclid keeping only the coefficient of `self`, reduced by the module
+    fn inverse(&self) -> Option<Self> {
+        let ring = &self.ring;
+        let (mut r0, mut r1) = (ring.module, self.value);
+        let (mut t0, mut t1) = (Uint::ZERO, Uint::from(1));
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            let qt = ring.mul_mod(&q.div_rem(&ring.module).1, &t1);
+            (t0, t1) = (t1, ring.sub_mod(&t0, &qt));
+        }
+        if r0 != Uint::from(1) {
+            return None;
+        }
+        Some(Self {
+            ring: *ring,
+            value: t0,
+        })
+    }
+}
+
+impl<const LIMBS: usize> Exponent for Uint<LIMBS> {
+    fn bits(&self) -> usize {
+        Uint::bits(self)
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        Uint::bit(self, i)
+    }
+}
@@ -0,0 +1,426 @@
+//! Binary fields `GF(2^n)` for `n <= 128`: elements are polynomials over `GF(2)` packed into
+//! the bits of a `u128`, bit `i` being the coefficient of `x^i`
+
+use std::{
+    cmp,
+    hash::Hash,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::{
+    factor::factorize,
+    field::Field,
+    ring::{Ring, RingElement},
+    units::element_order,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryFieldError {
+    /// Degree must be in `1..=128`
+    InvalidDegree(u32),
+    /// Module has bits at or above `x^degree`
+    ModuleOutOfRange { degree: u32, module: u128 },
+    /// `x^degree + module` factors over `GF(2)`
+    Reducible { degree: u32, module: u128 },
+}
+
+impl std::fmt::Display for BinaryFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryFieldError::InvalidDegree(degree) => {
+                write!(f, "Invalid binary field degree {degree}, expected 1..=128")
+            }
+            BinaryFieldError::ModuleOutOfRange { degree, module } => {
+                write!(f, "Module {module:#x} doesn't fit below x^{degree}")
+            }
+            BinaryFieldError::Reducible { degree, module } => {
+                write!(f, "x^{degree} + {module:#x} is reducible")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryFieldError {}
+
+/// `GF(2)[x] / (x^degree + module)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BinaryField {
+    degree: u32,
+    /// Irreducible polynomial without the leading `x^degree` term
+    module: u128,
+}
+
+impl BinaryField {
+    pub fn new(degree: u32, module: u128) -> Result<Self, BinaryFieldError> {
+        if !(1..=128).contains(&degree) {
+            return Err(BinaryFieldError::InvalidDegree(degree));
+        }
+        if degree < 128 && module >> degree != 0 {
+            return Err(BinaryFieldError::ModuleOutOfRange { degree, module });
+        }
+        let field = Self { degree, module };
+        if !field.is_irreducible() {
+            return Err(BinaryFieldError::Reducible { degree, module });
+        }
+        Ok(field)
+    }
+
+    /// AES field `x^8 + x^4 + x^3 + x + 1`
+    pub fn aes() -> Self {
+        Self::new(8, 0x1b).expect("Irreducible")
+    }
+
+    /// GCM field `x^128 + x^7 + x^2 + x + 1`
+    pub fn ghash() -> Self {
+        Self::new(128, 0x87).expect("Irreducible")
+    }
+
+    /// Field of the given degree with the numerically smallest irreducible module
+    pub fn with_smallest_module(degree: u32) -> Result<Self, BinaryFieldError> {
+        if !(1..=128).contains(&degree) {
+            return Err(BinaryFieldError::InvalidDegree(degree));
+        }
+        // Irreducible polynomials of degree > 1 have the constant term, and a trinomial or a
+        // pentanomial is found long before the search overflows
+        (0..=u128::MAX)
+            .filter(|module| degree == 1 || module & 1 == 1)
+            .find_map(|module| Self::new(degree, module).ok())
+            .ok_or(BinaryFieldError::Reducible { degree, module: 0 })
+    }
+
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    fn mask(&self) -> u128 {
+        u128::MAX >> (128 - self.degree)
+    }
+
+    /// Reduces a carry-less product `hi * x^128 + lo` of two reduced polynomials
+    fn reduce(&self, [mut lo, mut hi]: [u128; 2]) -> u128 {
+        let n = self.degree;
+        // Degree of the product is at most 2n - 2, clear bits from the top down to x^n
+        for k in (n..2 * n - 1).rev() {
+            let set = if k >= 128 {
+                (hi >> (k - 128)) & 1 == 1
+            } else {
+                (lo >> k) & 1 == 1
+            };
+            if !set {
+                continue;
+            }
+            // x^k = x^(k-n) * module
+            let shift = k - n;
+            if k >= 128 {
+                hi ^= 1 << (k - 128);
+            } else {
+                lo ^= 1 << k;
+            }
+            lo ^= self.module << shift;
+            if shift > 0 {
+                hi ^= self.module >> (128 - shift);
+            }
+        }
+        lo
+    }
+
+    fn mul_values(&self, a: u128, b: u128) -> u128 {
+        self.reduce(clmul(a, b))
+    }
+
+    /// `a^(2^k)` by `k` squarings
+    fn square_times(&self, mut a: u128, k: u32) -> u128 {
+        for _ in 0..k {
+            a = self.mul_values(a, a);
+        }
+        a
+    }
+
+    /// Rabin's test: `x^(2^n) = x` and `gcd(x^(2^(n/r)) - x, f) = 1` for every prime `r | n`
+    fn is_irreducible(&self) -> bool {
+        let n = self.degree;
+        if n == 1 {
+            return true;
+        }
+        if self.module & 1 == 0 {
+            // Divisible by x
+            return false;
+        }
+        let x = 0b10;
+        for r in factorize(n as u64).primes() {
+            let h = self.square_times(x, n / r as u32) ^ x;
+            if h == 0 || poly_gcd(h, self.module_rem(h)) != 1 {
+                return false;
+            }
+        }
+        self.square_times(x, n) == x
+    }
+
+    /// `(x^n + module) mod h` for a non-zero `h` of degree below `n`
+    fn module_rem(&self, h: u128) -> u128 {
+        if h == 1 {
+            return 0;
+        }
+        let h_degree = 127 - h.leading_zeros();
+        // x^n mod h by n multiplications by x
+        let mut r = 1;
+        for _ in 0..self.degree {
+            r <<= 1;
+            if (r >> h_degree) & 1 == 1 {
+                r ^= h;
+            }
+        }
+        r ^ poly_rem(self.module, h)
+    }
+}
+
+impl std::fmt::Display for BinaryField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GF(2^{}) (mod x^{}", self.degree, self.degree)?;
+        if self.module != 0 {
+            write!(f, " + {}", format_poly(self.module))?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl Ring for BinaryField {
+    type Element = BinaryFieldElement;
+    type Module = u128;
+    type Value = u128;
+
+    /// Creates an element dropping bits at and above `x^degree`
+    fn create_element(&self, value: Self::Value) -> Self::Element {
+        BinaryFieldElement {
+            field: *self,
+            value: value & self.mask(),
+        }
+    }
+
+    fn module(&self) -> &Self::Module {
+        &self.module
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.create_element(0)
+    }
+
+    fn one(&self) -> Self::Element {
+        self.create_element(1)
+    }
+
+    /// `2^degree`, `None` for degrees 64 and above
+    fn cardinality(&self) -> Option<u64> {
+        1u64.checked_shl(self.degree)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BinaryFieldElement {
+    field: BinaryField,
+    value: u128,
+}
+
+impl BinaryFieldElement {
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    fn check_field(&self, rhs: &Self) {
+        if self.field != rhs.field {
+            panic!(
+                "Field operation failed, lhs field: {}, rhs field: {}",
+                self.field, rhs.field
+            );
+        }
+    }
+}
+
+/// Addition is xor of coefficients
+impl Add for BinaryFieldElement {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        self.field.create_element(self.value ^ rhs.value)
+    }
+}
+
+/// Same as addition in characteristic 2
+impl Sub for BinaryFieldElement {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+/// Carry-less product reduced by the module
+impl Mul for BinaryFieldElement {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        self.field
+            .create_element(self.field.mul_values(self.value, rhs.value))
+    }
+}
+
+impl Div for BinaryFieldElement {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Remainder of the representative polynomials
+impl Rem for BinaryFieldElement {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        if rhs.value == 0 {
+            panic!("Field operation failed: Division by zero");
+        }
+        self.field.create_element(poly_rem(self.value, rhs.value))
+    }
+}
+
+impl Neg for BinaryFieldElement {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl PartialOrd for BinaryFieldElement {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BinaryFieldElement {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Polynomial form, e.g. `x^6 + x^4 + x + 1`
+impl std::fmt::Display for BinaryFieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_poly(self.value))
+    }
+}
+
+impl std::fmt::LowerHex for BinaryFieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl RingElement for BinaryFieldElement {
+    type Ring = BinaryField;
+
+    fn ring(&self) -> &Self::Ring {
+        &self.field
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        (self.value != 0).then(|| self.inv())
+    }
+
+    /// The unit group is cyclic of order `2^n - 1`, `None` for degrees above 64
+    fn multiplicative_order(&self) -> Option<u64> {
+        if self.field.degree > 64 || self.value == 0 {
+            return None;
+        }
+        Some(element_order(self, u64::MAX >> (64 - self.field.degree)))
+    }
+}
+
+impl Field for BinaryFieldElement {
+    /// Itoh-Tsujii: `a^-1 = (a^(2^(n-1) - 1))^2`, where `b_k = a^(2^k - 1)` is built by
+    /// `b_(2k) = b_k^(2^k) * b_k` and `b_(k+1) = b_k^2 * a` along the bits of `n - 1`
+    fn inv(&self) -> Self {
+        if self.value == 0 {
+            panic!("Inverse of zero in {}", self.field);
+        }
+        let field = &self.field;
+        let m = field.degree - 1;
+        if m == 0 {
+            return *self;
+        }
+        let a = self.value;
+        let mut beta = a;
+        let mut k = 1;
+        for i in (0..31 - m.leading_zeros()).rev() {
+            beta = field.mul_values(field.square_times(beta, k), beta);
+            k *= 2;
+            if (m >> i) & 1 == 1 {
+                beta = field.mul_values(field.mul_values(beta, beta), a);
+                k += 1;
+            }
+        }
+        field.create_element(field.mul_values(beta, beta))
+    }
+
+    fn characteristic(&self) -> u64 {
+        2
+    }
+
+    /// Squaring is a bijection in characteristic 2
+    fn legendre(&self) -> i8 {
+        i8::from(self.value != 0)
+    }
+
+    /// `a^(2^(n-1))`, the inverse of the Frobenius map
+    fn sqrt(&self) -> Option<Self> {
+        let field = &self.field;
+        Some(field.create_element(field.square_times(self.value, field.degree - 1)))
+    }
+}
+
+/// Carry-less product as `[lo, hi]` words
+fn clmul(a: u128, b: u128) -> [u128; 2] {
+    let (mut lo, mut hi) = (0, 0);
+    for i in 0..128 {
+        if (a >> i) & 1 == 1 {
+            lo ^= b << i;
+            if i > 0 {
+                hi ^= b >> (128 - i);
+            }
+        }
+    }
+    [lo, hi]
+}
+
+/// Remainder of polynomial division over `GF(2)`, `b` is non-zero
+fn poly_rem(mut a: u128, b: u128) -> u128 {
+    let b_degree = 127 - b.leading_zeros();
+    while a != 0 && 127 - a.leading_zeros() >= b_degree {
+        a ^= b << (127 - a.leading_zeros() - b_degree);
+    }
+    a
+}
+
+fn poly_gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, poly_rem(a, b));
+    }
+    a
+}
+
+fn format_poly(value: u128) -> String {
+    let terms: Vec<String> = (0..128)
+        .rev()
+        .filter(|i| (value >> i) & 1 == 1)
+        .map(|i| match i {
+            0 => "1".to_string(),
+            1 => "x".to_string(),
+            _ => format!("x^{i}"),
+        })
+        .collect();
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    terms.join(" + ")
+}
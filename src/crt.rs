@@ -0,0 +1,134 @@
+use crate::{
+    integer::gcd,
+    ring::{ExtendedEuclideanView, extended_euclidean},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrtError {
+    /// Congruence modules must be positive
+    InvalidModule(i64),
+    /// Congruence with index `index` contradicts the previous ones
+    Inconsistent { index: usize, gcd: i64 },
+    /// Lcm of modules doesn't fit into `i64`
+    Overflow,
+}
+
+impl std::fmt::Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::InvalidModule(module) => {
+                write!(f, "Invalid congruence module {module}, expected positive")
+            }
+            CrtError::Inconsistent { index, gcd } => write!(
+                f,
+                "Congruence #{} is inconsistent with the previous ones (mod gcd {gcd})",
+                index + 1
+            ),
+            CrtError::Overflow => f.write_str("Lcm of congruence modules overflows i64"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Merging of the accumulated solution `x = r (mod m)` with the next congruence `x = r_i (mod m_i)`
+#[derive(Debug, Clone)]
+pub struct CrtStep {
+    /// Accumulated solution `[r, m]`
+    pub acc: [i64; 2],
+    /// Next congruence `[r_i, m_i]`
+    pub congruence: [i64; 2],
+    /// gcd(m, m_i)
+    pub gcd: i64,
+    /// (m / gcd) * n + (m_i / gcd) * k = 1
+    pub bezout: [i64; 2],
+    /// Euclid steps for `bezout`, `None` if one of the reduced modules is 1
+    pub euclid: Option<ExtendedEuclideanView>,
+    /// x = r + m * t, t = (r_i - r) / gcd * n (mod m_i / gcd)
+    pub t: i64,
+    /// Merged solution `[r', lcm(m, m_i)]`
+    pub result: [i64; 2],
+}
+
+#[derive(Debug, Clone)]
+pub struct CrtView {
+    steps: Vec<CrtStep>,
+}
+
+impl CrtView {
+    pub fn steps(&self) -> &[CrtStep] {
+        &self.steps
+    }
+}
+
+impl std::fmt::Display for CrtView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for step in &self.steps {
+            let [r, m] = step.acc;
+            let [ri, mi] = step.congruence;
+            let [n, k] = step.bezout;
+            let [res, lcm] = step.result;
+            let g = step.gcd;
+            writeln!(f, "x = {r} (mod {m}), x = {ri} (mod {mi})")?;
+            writeln!(f, "  gcd({m}, {mi}) = {g}")?;
+            writeln!(f, "  {}*{n} + {}*{k} = 1", m / g, mi / g)?;
+            writeln!(
+                f,
+                "  x = {r} + {m}*t, t = ({ri} - {r}) / {g} * {n} = {} (mod {})",
+                step.t,
+                mi / g
+            )?;
+            writeln!(f, "  x = {res} (mod {lcm})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Solves a system of congruences `x = r_i (mod m_i)` given as `[r_i, m_i]` pairs.
+///
+/// Modules don't have to be coprime, the result is `(r, m)` with `m` the lcm of all modules
+/// and `0 <= r < m`. Empty system yields `x = 0 (mod 1)`.
+pub fn solve_crt(congruences: &[[i64; 2]]) -> Result<(i64, i64, CrtView), CrtError> {
+    let mut steps = vec![];
+    let mut acc = [0i64, 1i64];
+    for (index, &[residue, module]) in congruences.iter().enumerate() {
+        if module <= 0 {
+            return Err(CrtError::InvalidModule(module));
+        }
+        let congruence = [residue.rem_euclid(module), module];
+        if index == 0 {
+            acc = congruence;
+            continue;
+        }
+        let [r, m] = acc;
+        let g = gcd(m as u64, module as u64) as i64;
+        let diff = congruence[0] - r;
+        if diff % g != 0 {
+            return Err(CrtError::Inconsistent { index, gcd: g });
+        }
+        let (m_reduced, module_reduced) = (m / g, module / g);
+        let (bezout, euclid) = match extended_euclidean(m_reduced, module_reduced) {
+            Ok((n, k, view)) => ([n, k], Some(view)),
+            // One of the reduced modules is 1, so a Bezout pair is immediate
+            Err(_) if m_reduced == 1 => ([1, 0], None),
+            Err(_) => ([0, 1], None),
+        };
+        let t = ((diff / g) as i128 * bezout[0] as i128).rem_euclid(module_reduced as i128);
+        let lcm = m as i128 * module_reduced as i128;
+        if lcm > i64::MAX as i128 {
+            return Err(CrtError::Overflow);
+        }
+        let result = [(r as i128 + m as i128 * t) as i64, lcm as i64];
+        steps.push(CrtStep {
+            acc,
+            congruence,
+            gcd: g,
+            bezout,
+            euclid,
+            t: t as i64,
+            result,
+        });
+        acc = result;
+    }
+    Ok((acc[0], acc[1], CrtView { steps }))
+}
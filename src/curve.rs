@@ -0,0 +1,408 @@
+//! Elliptic curves in short Weierstrass form `y^2 = x^3 + a x + b`.
+//!
+//! The formulas need 2 and 3 to be invertible in the coefficient ring, so characteristic 2 and 3
+//! are rejected.
+
+use std::ops::{Add, Neg, Sub};
+
+use crate::ring::{Exponent, Ring, RingElement};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CurveError {
+    /// `4a^3 + 27b^2 = 0`, the curve has a cusp or a node
+    Singular,
+    /// 2 or 3 is not invertible in the coefficient ring, e.g. in characteristic 2 or 3
+    UnsupportedCharacteristic,
+    NotOnCurve,
+    /// Points belong to curves with different coefficients
+    CurveMismatch,
+    /// Slope denominator has no inverse, possible over rings with zero divisors
+    NotInvertible,
+}
+
+impl std::fmt::Display for CurveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurveError::Singular => f.write_str("Curve is singular"),
+            CurveError::UnsupportedCharacteristic => {
+                f.write_str("Short Weierstrass form needs 2 and 3 to be invertible")
+            }
+            CurveError::NotOnCurve => f.write_str("Point is not on the curve"),
+            CurveError::CurveMismatch => f.write_str("Points are on different curves"),
+            CurveError::NotInvertible => f.write_str("Slope denominator is not invertible"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
+/// Curve `y^2 = x^3 + a x + b`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Curve<E> {
+    a: E,
+    b: E,
+}
+
+impl<E: RingElement> Curve<E> {
+    pub fn new(a: E, b: E) -> Result<Self, CurveError> {
+        let one = a.ring().one();
+        if one.mul_u64(2).inverse().is_none() || one.mul_u64(3).inverse().is_none() {
+            return Err(CurveError::UnsupportedCharacteristic);
+        }
+        let curve = Self { a, b };
+        if curve.discriminant().is_zero() {
+            return Err(CurveError::Singular);
+        }
+        Ok(curve)
+    }
+
+    pub fn a(&self) -> E {
+        self.a
+    }
+
+    pub fn b(&self) -> E {
+        self.b
+    }
+
+    /// `4a^3 + 27b^2`, non-zero for smooth curves
+    pub fn discriminant(&self) -> E {
+        (self.a * self.a * self.a).mul_u64(4) + (self.b * self.b).mul_u64(27)
+    }
+
+    /// Right-hand side `x^3 + a x + b`
+    pub fn rhs(&self, x: &E) -> E {
+        *x * *x * *x + self.a * *x + self.b
+    }
+
+    pub fn contains(&self, x: &E, y: &E) -> bool {
+        *y * *y == self.rhs(x)
+    }
+
+    /// Affine point, fails if it doesn't satisfy the curve equation
+    pub fn point(&self, x: E, y: E) -> Result<AffinePoint<E>, CurveError> {
+        if !self.contains(&x, &y) {
+            return Err(CurveError::NotOnCurve);
+        }
+        Ok(AffinePoint {
+            curve: *self,
+            coordinates: Some((x, y)),
+        })
+    }
+
+    pub fn infinity(&self) -> AffinePoint<E> {
+        AffinePoint {
+            curve: *self,
+            coordinates: None,
+        }
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for Curve<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "y^2 = x^3 + {}x + {}", self.a, self.b)
+    }
+}
+
+/// Point `(x, y)` of a curve or the point at infinity `O`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffinePoint<E> {
+    curve: Curve<E>,
+    /// `None` for the point at infinity
+    coordinates: Option<(E, E)>,
+}
+
+impl<E: RingElement> AffinePoint<E> {
+    pub fn curve(&self) -> &Curve<E> {
+        &self.curve
+    }
+
+    pub fn coordinates(&self) -> Option<(E, E)> {
+        self.coordinates
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.coordinates.is_none()
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        self.coordinates
+            .is_none_or(|(x, y)| self.curve.contains(&x, &y))
+    }
+
+    pub fn to_jacobian(&self) -> JacobianPoint<E> {
+        let one = self.curve.a.ring().one();
+        let (x, y, z) = match self.coordinates {
+            Some((x, y)) => (x, y, one),
+            None => (one, one, self.curve.a.ring().zero()),
+        };
+        JacobianPoint {
+            curve: self.curve,
+            x,
+            y,
+            z,
+        }
+    }
+
+    fn check_curve(&self, rhs: &Self) -> Result<(), CurveError> {
+        if self.curve != rhs.curve {
+            return Err(CurveError::CurveMismatch);
+        }
+        Ok(())
+    }
+
+    /// Chord rule with slope `(y2 - y1) / (x2 - x1)`
+    pub fn checked_add(self, rhs: Self) -> Result<Self, CurveError> {
+        self.check_curve(&rhs)?;
+        let (Some((x1, y1)), Some((x2, y2))) = (self.coordinates, rhs.coordinates) else {
+            return Ok(if self.is_infinity() { rhs } else { self });
+        };
+        if x1 == x2 {
+            if (y1 + y2).is_zero() {
+                return Ok(self.curve.infinity());
+            }
+            return self.checked_double();
+        }
+        let inv = (x2 - x1).inverse().ok_or(CurveError::NotInvertible)?;
+        Ok(self.with_slope((y2 - y1) * inv, x2))
+    }
+
+    /// Tangent rule with slope `(3x^2 + a) / 2y`
+    pub fn checked_double(self) -> Result<Self, CurveError> {
+        let Some((x, y)) = self.coordinates else {
+            return Ok(self);
+        };
+        if y.is_zero() {
+            return Ok(self.curve.infinity());
+        }
+        let inv = y.mul_u64(2).inverse().ok_or(CurveError::NotInvertible)?;
+        Ok(self.with_slope(((x * x).mul_u64(3) + self.curve.a) * inv, x))
+    }
+
+    /// Third intersection of the line through `self` with `slope`, reflected over the x axis
+    fn with_slope(&self, slope: E, other_x: E) -> Self {
+        let (x1, y1) = self.coordinates.expect("Finite point");
+        let x3 = slope * slope - x1 - other_x;
+        let y3 = slope * (x1 - x3) - y1;
+        Self {
+            curve: self.curve,
+            coordinates: Some((x3, y3)),
+        }
+    }
+
+    pub fn double(&self) -> Self {
+        self.checked_double()
+            .unwrap_or_else(|e| panic!("Curve operation failed: {e}"))
+    }
+
+    /// `k * self` by double-and-add in Jacobian coordinates
+    pub fn scalar_mul(&self, k: impl Exponent) -> Self {
+        self.to_jacobian()
+            .scalar_mul(k)
+            .to_affine()
+            .unwrap_or_else(|e| panic!("Curve operation failed: {e}"))
+    }
+}
+
+/// Operators panic where the `checked_*` counterparts return an error
+impl<E: RingElement> Add for AffinePoint<E> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|e| panic!("Curve operation failed: {e}"))
+    }
+}
+
+impl<E: RingElement> Sub for AffinePoint<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<E: RingElement> Neg for AffinePoint<E> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            curve: self.curve,
+            coordinates: self.coordinates.map(|(x, y)| (x, -y)),
+        }
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for AffinePoint<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.coordinates {
+            Some((x, y)) => write!(f, "({x}, {y})"),
+            None => f.write_str("O"),
+        }
+    }
+}
+
+/// Point `(X : Y : Z)` representing `(X / Z^2, Y / Z^3)`, infinity has `Z = 0`.
+///
+/// Addition and doubling need no inversions.
+#[derive(Debug, Clone, Copy)]
+pub struct JacobianPoint<E> {
+    curve: Curve<E>,
+    x: E,
+    y: E,
+    z: E,
+}
+
+impl<E: RingElement> JacobianPoint<E> {
+    pub fn curve(&self) -> &Curve<E> {
+        &self.curve
+    }
+
+    pub fn coordinates(&self) -> (E, E, E) {
+        (self.x, self.y, self.z)
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// `Y^2 = X^3 + a X Z^4 + b Z^6`
+    pub fn is_on_curve(&self) -> bool {
+        let z2 = self.z * self.z;
+        let z4 = z2 * z2;
+        self.y * self.y
+            == self.x * self.x * self.x + self.curve.a * self.x * z4 + self.curve.b * z4 * z2
+    }
+
+    pub fn to_affine(&self) -> Result<AffinePoint<E>, CurveError> {
+        if self.is_infinity() {
+            return Ok(self.curve.infinity());
+        }
+        let z_inv = self.z.inverse().ok_or(CurveError::NotInvertible)?;
+        let z_inv2 = z_inv * z_inv;
+        Ok(AffinePoint {
+            curve: self.curve,
+            coordinates: Some((self.x * z_inv2, self.y * z_inv2 * z_inv)),
+        })
+    }
+
+    fn infinity(&self) -> Self {
+        self.curve.infinity().to_jacobian()
+    }
+
+    /// `S = 4XY^2`, `M = 3X^2 + aZ^4`, `X' = M^2 - 2S`, `Y' = M(S - X') - 8Y^4`, `Z' = 2YZ`
+    pub fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return self.infinity();
+        }
+        let (x, y, z) = (self.x, self.y, self.z);
+        let y2 = y * y;
+        let z2 = z * z;
+        let s = (x * y2).mul_u64(4);
+        let m = (x * x).mul_u64(3) + self.curve.a * z2 * z2;
+        let x3 = m * m - s.mul_u64(2);
+        let y3 = m * (s - x3) - (y2 * y2).mul_u64(8);
+        let z3 = (y * z).mul_u64(2);
+        Self {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Left-to-right double-and-add
+    pub fn scalar_mul(&self, k: impl Exponent) -> Self {
+        let mut result = self.infinity();
+        for i in (0..k.bits()).rev() {
+            result = result.double();
+            if k.bit(i) {
+                result = result + *self;
+            }
+        }
+        result
+    }
+}
+
+/// `U1 = X1 Z2^2`, `U2 = X2 Z1^2`, `S1 = Y1 Z2^3`, `S2 = Y2 Z1^3`, `H = U2 - U1`,
+/// `R = S2 - S1`, `X3 = R^2 - H^3 - 2 U1 H^2`, `Y3 = R (U1 H^2 - X3) - S1 H^3`, `Z3 = H Z1 Z2`
+impl<E: RingElement> Add for JacobianPoint<E> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.curve != rhs.curve {
+            panic!("Curve operation failed: {}", CurveError::CurveMismatch);
+        }
+        if self.is_infinity() {
+            return rhs;
+        }
+        if rhs.is_infinity() {
+            return self;
+        }
+        let z1z1 = self.z * self.z;
+        let z2z2 = rhs.z * rhs.z;
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * z2z2 * rhs.z;
+        let s2 = rhs.y * z1z1 * self.z;
+        if u1 == u2 {
+            if s1 == s2 {
+                return self.double();
+            }
+            return self.infinity();
+        }
+        let h = u2 - u1;
+        let r = s2 - s1;
+        let h2 = h * h;
+        let h3 = h2 * h;
+        let u1h2 = u1 * h2;
+        let x3 = r * r - h3 - u1h2.mul_u64(2);
+        let y3 = r * (u1h2 - x3) - s1 * h3;
+        let z3 = h * self.z * rhs.z;
+        Self {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<E: RingElement> Sub for JacobianPoint<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<E: RingElement> Neg for JacobianPoint<E> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { y: -self.y, ..self }
+    }
+}
+
+/// Same point, possibly with different representatives: `X1 Z2^2 = X2 Z1^2` and
+/// `Y1 Z2^3 = Y2 Z1^3`
+impl<E: RingElement> PartialEq for JacobianPoint<E> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.curve != other.curve {
+            return false;
+        }
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() == other.is_infinity();
+        }
+        let z1z1 = self.z * self.z;
+        let z2z2 = other.z * other.z;
+        self.x * z2z2 == other.x * z1z1 && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+    }
+}
+
+impl<E: RingElement> Eq for JacobianPoint<E> {}
+
+impl<E: RingElement> From<AffinePoint<E>> for JacobianPoint<E> {
+    fn from(point: AffinePoint<E>) -> Self {
+        point.to_jacobian()
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for JacobianPoint<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} : {} : {})", self.x, self.y, self.z)
+    }
+}
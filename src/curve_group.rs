@@ -0,0 +1,207 @@
+//! Group of points of a curve over a small prime field `Z/pZ`: point counting, structure and
+//! prime order subgroups.
+//!
+//! Field elements are enumerated as `0, 1, ..., p - 1`, so the coefficient ring must be a prime
+//! field such as `SmallRing` with a prime module or `PrimeField`. Functions return `None` for
+//! other rings, e.g. extension fields whose elements aren't multiples of one.
+
+use std::collections::HashMap;
+
+use crate::{
+    curve::{AffinePoint, Curve},
+    factor::{Factorization, factorize},
+    params::SeededRng,
+    prime::is_prime,
+    quadratic::field_legendre,
+    ring::{Ring, RingElement, SmallRing, SmallRingElement},
+    units::order_dividing,
+};
+
+/// Random curves tried by `find_prime_order_curve` before giving up
+const MAX_ATTEMPTS: usize = 10_000;
+
+/// `p` if the coefficients form a prime field `F_p`
+fn prime_field_size<E: RingElement>(curve: &Curve<E>) -> Option<u64> {
+    curve.a().ring().cardinality().filter(|p| is_prime(*p))
+}
+
+/// `0, 1, ..., p - 1`, `None` unless the coefficients form a prime field
+fn elements<E: RingElement>(curve: &Curve<E>) -> Option<impl Iterator<Item = E>> {
+    let p = prime_field_size(curve)?;
+    let one = curve.a().ring().one();
+    Some((0..p).map(move |x| one.mul_u64(x)))
+}
+
+/// All points including infinity, ordered by `x` then `y`
+pub fn points<E: RingElement>(curve: &Curve<E>) -> Option<Vec<AffinePoint<E>>> {
+    let mut roots: HashMap<E, Vec<E>> = HashMap::new();
+    for y in elements(curve)? {
+        roots.entry(y * y).or_default().push(y);
+    }
+    let mut points = vec![curve.infinity()];
+    for x in elements(curve)? {
+        for y in roots.get(&curve.rhs(&x)).into_iter().flatten() {
+            points.push(curve.point(x, *y).expect("y^2 = rhs"));
+        }
+    }
+    Some(points)
+}
+
+/// `#E(F_p)` by enumerating all points
+pub fn count_points_naive<E: RingElement>(curve: &Curve<E>) -> Option<u64> {
+    Some(points(curve)?.len() as u64)
+}
+
+/// `#E(F_p) = p + 1 + sum_x (rhs(x) / p)`: every `x` contributes `1 + (rhs(x) / p)` points.
+///
+/// `p` is odd since curves need characteristic above 3.
+pub fn count_points<E: RingElement>(curve: &Curve<E>) -> Option<u64> {
+    let p = prime_field_size(curve)?;
+    let sum: i64 = elements(curve)?
+        .map(|x| field_legendre(&curve.rhs(&x), p) as i64)
+        .sum();
+    Some((p as i64 + 1 + sum) as u64)
+}
+
+/// Order of a point given a multiple of it, e.g. the group order
+pub fn point_order<E: RingElement>(point: &AffinePoint<E>, multiple: u64) -> u64 {
+    order_dividing(multiple, &factorize(multiple), |k| {
+        point.scalar_mul(k).is_infinity()
+    })
+}
+
+/// `E(F_p) = Z/n1 x Z/n2` with `n2 | n1`, where `n1` is the group exponent
+#[derive(Debug, Clone)]
+pub struct CurveGroup<E> {
+    pub order: u64,
+    /// `n1`, the largest point order
+    pub exponent: u64,
+    /// Point of order `exponent`
+    pub generator: AffinePoint<E>,
+}
+
+impl<E> CurveGroup<E> {
+    pub fn is_cyclic(&self) -> bool {
+        self.order == self.exponent
+    }
+
+    /// `[n1, n2]`
+    pub fn invariants(&self) -> [u64; 2] {
+        [self.exponent, self.order / self.exponent]
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for CurveGroup<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [n1, n2] = self.invariants();
+        write!(f, "order {}, Z/{n1}", self.order)?;
+        if n2 > 1 {
+            write!(f, " x Z/{n2}")?;
+        }
+        write!(f, ", point of maximal order {}", self.generator)
+    }
+}
+
+/// Group structure by the orders of all points
+pub fn curve_group<E: RingElement>(curve: &Curve<E>) -> Option<CurveGroup<E>> {
+    let points = points(curve)?;
+    let order = points.len() as u64;
+    let factorization = factorize(order);
+    let mut exponent = 1;
+    let mut generator = curve.infinity();
+    for point in &points {
+        let point_order =
+            order_dividing(order, &factorization, |k| point.scalar_mul(k).is_infinity());
+        // The exponent is the largest point order in an abelian group
+        if point_order > exponent {
+            exponent = point_order;
+            generator = *point;
+        }
+        if exponent == order {
+            break;
+        }
+    }
+    Some(CurveGroup {
+        order,
+        exponent,
+        generator,
+    })
+}
+
+/// Subgroup of the largest prime order `q` dividing `#E`
+#[derive(Debug, Clone)]
+pub struct PrimeSubgroup<E> {
+    /// `#E(F_p)`
+    pub curve_order: u64,
+    pub order: u64,
+    /// `#E / q`
+    pub cofactor: u64,
+    pub generator: AffinePoint<E>,
+}
+
+impl<E: RingElement> std::fmt::Display for PrimeSubgroup<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#E = {} = {} * {}, generator {} of order {}",
+            self.curve_order, self.cofactor, self.order, self.generator, self.order
+        )
+    }
+}
+
+pub fn cofactor(curve_order: &Factorization) -> u64 {
+    let q = curve_order.primes().last().unwrap_or(1);
+    curve_order.value() / q
+}
+
+/// Point of the largest prime order `q` dividing `#E`: `P` is multiplied by the part of `#E`
+/// coprime to `q` and then by `q` while the result stays finite. `None` if the curve has only
+/// the point at infinity.
+pub fn prime_subgroup<E: RingElement>(curve: &Curve<E>) -> Option<PrimeSubgroup<E>> {
+    let points = points(curve)?;
+    let curve_order = points.len() as u64;
+    let factorization = factorize(curve_order);
+    let &(order, exponent) = factorization.factors().last()?;
+    let coprime_part = curve_order / order.pow(exponent);
+    let generator = points
+        .iter()
+        .map(|point| point.scalar_mul(coprime_part))
+        .find(|point| !point.is_infinity())
+        .map(|mut point| {
+            // Order of the point is a power of q
+            while !point.scalar_mul(order).is_infinity() {
+                point = point.scalar_mul(order);
+            }
+            point
+        })?;
+    Some(PrimeSubgroup {
+        curve_order,
+        order,
+        cofactor: cofactor(&factorization),
+        generator,
+    })
+}
+
+/// Random curve over the prime field `ring` whose number of points is prime, `None` if the
+/// module is not a prime above 3
+pub fn find_prime_order_curve(
+    ring: &SmallRing,
+    rng: &mut SeededRng,
+) -> Option<(Curve<SmallRingElement>, u64)> {
+    let p = *ring.module() as u64;
+    if p <= 3 || !is_prime(p) {
+        return None;
+    }
+    for _ in 0..MAX_ATTEMPTS {
+        let a = ring.create_element(rng.gen_range(0..=p - 1).ok()?);
+        let b = ring.create_element(rng.gen_range(0..=p - 1).ok()?);
+        let Ok(curve) = Curve::new(a, b) else {
+            continue;
+        };
+        let order = count_points(&curve)?;
+        if is_prime(order) {
+            return Some((curve, order));
+        }
+    }
+    None
+}
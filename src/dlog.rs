@@ -0,0 +1,233 @@
+//! Discrete logarithm: find `x` with `g^x = h` in a group, e.g. `k` with `k * P = Q` on a curve
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::{
+    crt::solve_crt,
+    factor::factorize,
+    group::Group,
+    integer::{gcd, inverse_mod},
+};
+
+/// Starting points tried by Pollard's rho before giving up
+const RHO_ATTEMPTS: u64 = 32;
+/// Orders below this are left to baby-step giant-step
+const RHO_MIN_ORDER: u64 = 16;
+
+/// Table of baby steps `g^j` and giant steps `h * g^(-m i)` until the first match
+#[derive(Debug, Clone)]
+pub struct BabyStepGiantStepView<G> {
+    m: u64,
+    baby_steps: Vec<G>,
+    /// `g^-m`
+    giant_step: G,
+    giant_steps: Vec<G>,
+    /// `[i, j]` with `h * g^(-m i) = g^j`
+    solution: Option<[u64; 2]>,
+}
+
+impl<G> BabyStepGiantStepView<G> {
+    pub fn m(&self) -> u64 {
+        self.m
+    }
+
+    /// `g^j` for `j = 0, ..., m - 1`
+    pub fn baby_steps(&self) -> &[G] {
+        &self.baby_steps
+    }
+
+    pub fn giant_step(&self) -> &G {
+        &self.giant_step
+    }
+
+    /// `h * g^(-m i)` for `i = 0, 1, ...` up to the match
+    pub fn giant_steps(&self) -> &[G] {
+        &self.giant_steps
+    }
+
+    pub fn solution(&self) -> Option<[u64; 2]> {
+        self.solution
+    }
+}
+
+impl<G: Group> std::fmt::Display for BabyStepGiantStepView<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let m = self.m;
+        writeln!(f, "m = ceil(sqrt(n)) = {m}")?;
+        writeln!(f, "baby steps g^j:")?;
+        for (j, step) in self.baby_steps.iter().enumerate() {
+            writeln!(f, "  j = {j}: {step}")?;
+        }
+        writeln!(f, "giant steps h * (g^-m)^i, g^-m = {}:", self.giant_step)?;
+        for (i, step) in self.giant_steps.iter().enumerate() {
+            writeln!(f, "  i = {i}: {step}")?;
+        }
+        match self.solution {
+            Some([i, j]) => writeln!(f, "x = i * m + j = {i} * {m} + {j} = {}", i * m + j),
+            None => writeln!(f, "no match"),
+        }
+    }
+}
+
+/// Baby-step giant-step in `O(sqrt(order))` time and memory.
+///
+/// `order` is the order of `g` or any multiple of it, the result is the smallest `x < order`.
+pub fn baby_step_giant_step<G: Group>(g: &G, h: &G, order: u64) -> Option<u64> {
+    baby_step_giant_step_inner(g, h, order, None)
+}
+
+/// Same as `baby_step_giant_step`, but records all baby steps and the giant steps
+pub fn baby_step_giant_step_with_view<G: Group>(
+    g: &G,
+    h: &G,
+    order: u64,
+) -> (Option<u64>, BabyStepGiantStepView<G>) {
+    let mut view = BabyStepGiantStepView {
+        m: 0,
+        baby_steps: vec![],
+        giant_step: g.identity(),
+        giant_steps: vec![],
+        solution: None,
+    };
+    let x = baby_step_giant_step_inner(g, h, order, Some(&mut view));
+    (x, view)
+}
+
+fn baby_step_giant_step_inner<G: Group>(
+    g: &G,
+    h: &G,
+    order: u64,
+    mut view: Option<&mut BabyStepGiantStepView<G>>,
+) -> Option<u64> {
+    let m = order.isqrt() + u64::from(order.isqrt().pow(2) != order);
+    let mut baby_steps = HashMap::new();
+    let mut current = g.identity();
+    for j in 0..m {
+        baby_steps.entry(current).or_insert(j);
+        if let Some(view) = view.as_deref_mut() {
+            view.baby_steps.push(current);
+        }
+        current = current.operate(g);
+    }
+    // current = g^m
+    let giant_step = current.inverse();
+    if let Some(view) = view.as_deref_mut() {
+        view.m = m;
+        view.giant_step = giant_step;
+    }
+    let mut gamma = *h;
+    for i in 0..m {
+        if let Some(view) = view.as_deref_mut() {
+            view.giant_steps.push(gamma);
+        }
+        if let Some(&j) = baby_steps.get(&gamma) {
+            let x = i * m + j;
+            if let Some(view) = view.as_deref_mut() {
+                view.solution = Some([i, j]);
+            }
+            return (x < order).then_some(x);
+        }
+        gamma = gamma.operate(&giant_step);
+    }
+    None
+}
+
+/// Pollard's rho for logarithms with Floyd cycle detection in `O(sqrt(order))` time and
+/// constant memory. Works best when `order` is the prime order of `g`.
+pub fn pollard_rho_log<G: Group>(g: &G, h: &G, order: u64) -> Option<u64> {
+    if order == 0 {
+        return None;
+    }
+    if order < RHO_MIN_ORDER {
+        // Walks in tiny groups collide degenerately, solve them directly
+        return baby_step_giant_step(g, h, order);
+    }
+    let n = order as u128;
+    // Walk x -> x*g, x^2 or x*h depending on the partition of x, tracking x = g^a * h^b
+    let step = |(x, a, b): (G, u128, u128)| match partition(&x) {
+        0 => (x.operate(g), (a + 1) % n, b),
+        1 => (x.operate(&x), a * 2 % n, b * 2 % n),
+        _ => (x.operate(h), a, (b + 1) % n),
+    };
+    for attempt in 0..RHO_ATTEMPTS {
+        let a0 = (attempt as u128 * 7919 + 1) % n;
+        let start = (g.pow(a0).operate(h), a0, 1);
+        let (mut tortoise, mut hare) = (step(start), step(step(start)));
+        for _ in 0..4 * order.isqrt() + 16 {
+            if tortoise.0 == hare.0 {
+                break;
+            }
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        if tortoise.0 != hare.0 {
+            continue;
+        }
+        // g^a1 h^b1 = g^a2 h^b2  =>  (b1 - b2) x = a2 - a1 (mod n)
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        let lhs = (b1 + n - b2) % n;
+        let rhs = (a2 + n - a1) % n;
+        if lhs == 0 {
+            // Degenerate collision, every x fits
+            continue;
+        }
+        if let Some(x) = solve_linear(lhs, rhs, n).find(|x| g.pow(*x) == *h) {
+            return Some(x as u64);
+        }
+    }
+    None
+}
+
+/// Pohlig-Hellman: solves the logarithm in every prime power subgroup of `<g>` and combines
+/// the results with the CRT. Fast when `order` is smooth.
+///
+/// `order` must be the exact order of `g`, the digits are read off subgroups of that size.
+pub fn pohlig_hellman<G: Group>(g: &G, h: &G, order: u64) -> Option<u64> {
+    if order == 0 {
+        return None;
+    }
+    let mut congruences = vec![];
+    for (q, e) in factorize(order).factors() {
+        let q_e = q.pow(*e);
+        let cofactor = order / q_e;
+        let (g_i, h_i) = (g.pow(cofactor), h.pow(cofactor));
+        // gamma has order q, x_i = d_0 + d_1 q + ... + d_(e-1) q^(e-1)
+        let gamma = g_i.pow(q_e / q);
+        let g_i_inv = g_i.inverse();
+        let mut x_i = 0;
+        let mut q_k = 1;
+        for _ in 0..*e {
+            let h_k = g_i_inv.pow(x_i).operate(&h_i).pow(q_e / q_k / q);
+            let d = baby_step_giant_step(&gamma, &h_k, *q)?;
+            x_i += d * q_k;
+            q_k *= q;
+        }
+        congruences.push([x_i as i64, q_e as i64]);
+    }
+    let (x, _, _) = solve_crt(&congruences).ok()?;
+    (g.pow(x as u64) == *h).then_some(x as u64)
+}
+
+/// Solutions of `a x = b (mod n)`
+fn solve_linear(a: u128, b: u128, n: u128) -> impl Iterator<Item = u128> {
+    let d = gcd(a as u64, n as u64) as u128;
+    let solvable = b.is_multiple_of(d);
+    let (a, b, m) = (a / d, b / d, n / d);
+    let base = if solvable && m > 1 {
+        let a_inv = inverse_mod(a as u64, m as u64).expect("a / d and n / d are coprime");
+        b * a_inv as u128 % m
+    } else {
+        0
+    };
+    (0..if solvable { d } else { 0 }).map(move |k| base + k * m)
+}
+
+fn partition<E: Hash>(x: &E) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish() % 3
+}
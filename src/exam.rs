@@ -0,0 +1,377 @@
+use std::{collections::BTreeMap, path::Path};
+
+use handlebars::Handlebars;
+
+use crate::{
+    crt::{CrtError, solve_crt},
+    curve::AffinePoint,
+    dlog::baby_step_giant_step_with_view,
+    params::{
+        CrtConstraints, ParamsError, SeededRng, crt_params, ecdlp_params, euclid_params,
+        inverse_params, shamir_params,
+    },
+    ring::{RingElement, SmallRingElement, extended_euclidean},
+    shamir::{Share, reconstruct},
+};
+
+#[derive(Debug)]
+pub enum ExamError {
+    Io(std::io::Error),
+    Template(Box<handlebars::TemplateError>),
+    Render(Box<handlebars::RenderError>),
+    /// `[[name]]` placeholder without a value in task params
+    MissingParam {
+        template: String,
+        name: String,
+    },
+    /// Task parameters don't define a solvable task
+    InvalidTask(String),
+    Params(ParamsError),
+}
+
+impl std::fmt::Display for ExamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExamError::Io(e) => write!(f, "Io error: {e}"),
+            ExamError::Template(e) => write!(f, "Template error: {e}"),
+            ExamError::Render(e) => write!(f, "Render error: {e}"),
+            ExamError::MissingParam { template, name } => {
+                write!(
+                    f,
+                    "Template `{template}` uses `[[{name}]]` which is not set"
+                )
+            }
+            ExamError::InvalidTask(reason) => write!(f, "Invalid task: {reason}"),
+            ExamError::Params(e) => write!(f, "Params error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ExamError {}
+
+impl From<std::io::Error> for ExamError {
+    fn from(e: std::io::Error) -> Self {
+        ExamError::Io(e)
+    }
+}
+
+impl From<ParamsError> for ExamError {
+    fn from(e: ParamsError) -> Self {
+        ExamError::Params(e)
+    }
+}
+
+impl From<handlebars::TemplateError> for ExamError {
+    fn from(e: handlebars::TemplateError) -> Self {
+        ExamError::Template(Box::new(e))
+    }
+}
+
+impl From<handlebars::RenderError> for ExamError {
+    fn from(e: handlebars::RenderError) -> Self {
+        ExamError::Render(Box::new(e))
+    }
+}
+
+/// A single exam task rendered from a template in the templates directory
+pub trait Task {
+    /// Template file name without the `.md` extension
+    fn template(&self) -> &str;
+    /// Values for `{{ }}` and `[[ ]]` placeholders
+    fn params(&self) -> BTreeMap<String, String>;
+    /// Markdown answer with the worked solution
+    fn answer(&self) -> Result<String, ExamError>;
+}
+
+/// Renders tasks from markdown templates.
+///
+/// Templates are rendered in two passes. Handlebars fills `{{name}}` placeholders first, then
+/// `[[name]]` placeholders are substituted verbatim. The latter is meant for math blocks where
+/// `{{` clashes with latex braces, e.g. `\pmod{[[mod_1]]}`.
+pub struct ExamGenerator {
+    registry: Handlebars<'static>,
+}
+
+impl ExamGenerator {
+    /// Registers every `*.md` file in `dir` as a template named by its file stem
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, ExamError> {
+        let mut registry = Handlebars::new();
+        registry.set_strict_mode(true);
+        registry.register_escape_fn(handlebars::no_escape);
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let source = std::fs::read_to_string(&path)?;
+            registry.register_template_string(name, source)?;
+        }
+        Ok(Self { registry })
+    }
+
+    pub fn render_task(&self, task_number: usize, task: &dyn Task) -> Result<String, ExamError> {
+        let mut params = task.params();
+        params.insert("task_number".to_string(), task_number.to_string());
+        let rendered = self.registry.render(task.template(), &params)?;
+        substitute_brackets(task.template(), &rendered, &params)
+    }
+
+    /// Renders numbered tasks into an exam document and its answer key
+    pub fn generate(&self, title: &str, tasks: &[Box<dyn Task>]) -> Result<Exam, ExamError> {
+        let mut document = format!("# {title}\n\n");
+        let mut answers = format!("# {title}: answers\n\n");
+        for (i, task) in tasks.iter().enumerate() {
+            let task_number = i + 1;
+            document.push_str(&self.render_task(task_number, task.as_ref())?);
+            document.push('\n');
+            answers.push_str(&format!("## Task {task_number}\n\n{}\n", task.answer()?));
+        }
+        Ok(Exam { document, answers })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Exam {
+    pub document: String,
+    pub answers: String,
+}
+
+impl Exam {
+    /// Writes `<name>.md` and `<name>_answers.md` into `dir`
+    pub fn write(&self, dir: impl AsRef<Path>, name: &str) -> Result<(), ExamError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(format!("{name}.md")), &self.document)?;
+        std::fs::write(dir.join(format!("{name}_answers.md")), &self.answers)?;
+        Ok(())
+    }
+}
+
+fn substitute_brackets(
+    template: &str,
+    source: &str,
+    params: &BTreeMap<String, String>,
+) -> Result<String, ExamError> {
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("[[") {
+        let Some(len) = rest[start + 2..].find("]]") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        let value = params.get(name).ok_or_else(|| ExamError::MissingParam {
+            template: template.to_string(),
+            name: name.to_string(),
+        })?;
+        result.push_str(&rest[..start]);
+        result.push_str(value);
+        rest = &rest[start + 2 + len + 2..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Task for `templates/crt.md`: solve system of congruences `x = x_i (mod mod_i)`
+#[derive(Debug, Clone)]
+pub struct CrtTask {
+    congruences: Vec<[i64; 2]>,
+}
+
+impl CrtTask {
+    pub fn new(congruences: Vec<[i64; 2]>) -> Self {
+        Self { congruences }
+    }
+}
+
+impl Task for CrtTask {
+    fn template(&self) -> &str {
+        "crt"
+    }
+
+    fn params(&self) -> BTreeMap<String, String> {
+        let mut params = BTreeMap::new();
+        for (i, [residue, module]) in self.congruences.iter().enumerate() {
+            params.insert(format!("x_{}", i + 1), residue.to_string());
+            params.insert(format!("mod_{}", i + 1), module.to_string());
+        }
+        params
+    }
+
+    fn answer(&self) -> Result<String, ExamError> {
+        let (x, module, view) = solve_crt(&self.congruences)
+            .map_err(|e: CrtError| ExamError::InvalidTask(e.to_string()))?;
+        Ok(format!("$x = {x} \\pmod{{{module}}}$\n\n```\n{view}```\n"))
+    }
+}
+
+/// Task for `templates/euclid.md`: find Bezout coefficients `x*a + y*b = 1`
+#[derive(Debug, Clone)]
+pub struct EuclidTask {
+    x: i64,
+    y: i64,
+}
+
+impl EuclidTask {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Task for EuclidTask {
+    fn template(&self) -> &str {
+        "euclid"
+    }
+
+    fn params(&self) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("x".to_string(), self.x.to_string()),
+            ("y".to_string(), self.y.to_string()),
+        ])
+    }
+
+    fn answer(&self) -> Result<String, ExamError> {
+        let (a, b, view) = extended_euclidean(self.x, self.y)
+            .map_err(|gcd| ExamError::InvalidTask(format!("gcd is {gcd}")))?;
+        Ok(format!("$a = {a}, b = {b}$\n\n```\n{view}```\n"))
+    }
+}
+
+/// Task for `templates/inverse.md`: find the inverse of an element of `SmallRing`
+#[derive(Debug, Clone)]
+pub struct InverseTask {
+    element: SmallRingElement,
+}
+
+impl InverseTask {
+    pub fn new(element: SmallRingElement) -> Self {
+        Self { element }
+    }
+}
+
+impl Task for InverseTask {
+    fn template(&self) -> &str {
+        "inverse"
+    }
+
+    fn params(&self) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("value".to_string(), self.element.to_string()),
+            ("module".to_string(), self.element.module().to_string()),
+        ])
+    }
+
+    fn answer(&self) -> Result<String, ExamError> {
+        let module = self.element.module();
+        let inverse = self
+            .element
+            .inverse()
+            .ok_or_else(|| ExamError::InvalidTask(format!("{} is not a unit", self.element)))?;
+        let view = extended_euclidean(self.element.value() as i64, module as i64)
+            .map_err(|gcd| ExamError::InvalidTask(format!("gcd is {gcd}")))?
+            .2;
+        Ok(format!("${inverse}$\n\n```\n{view}```\n"))
+    }
+}
+
+/// Task for `templates/shamir.md`: recover the secret from `threshold` shares
+#[derive(Debug, Clone)]
+pub struct ShamirTask {
+    shares: Vec<Share<SmallRingElement>>,
+}
+
+impl ShamirTask {
+    pub fn new(shares: Vec<Share<SmallRingElement>>) -> Self {
+        Self { shares }
+    }
+}
+
+impl Task for ShamirTask {
+    fn template(&self) -> &str {
+        "shamir"
+    }
+
+    fn params(&self) -> BTreeMap<String, String> {
+        let shares: Vec<String> = self.shares.iter().map(Share::to_string).collect();
+        let module = self.shares.first().map_or(0, |share| share.x.module());
+        BTreeMap::from([
+            ("shares".to_string(), shares.join(", ")),
+            ("threshold".to_string(), self.shares.len().to_string()),
+            ("module".to_string(), module.to_string()),
+        ])
+    }
+
+    fn answer(&self) -> Result<String, ExamError> {
+        let (secret, view) = reconstruct(&self.shares, self.shares.len())
+            .map_err(|e| ExamError::InvalidTask(e.to_string()))?;
+        Ok(format!("$s = {secret}$\n\n```\n{view}```\n"))
+    }
+}
+
+/// Task for `templates/ecdlp.md`: find `k` with `k * P = Q` on a curve
+#[derive(Debug, Clone)]
+pub struct EcdlpTask {
+    p: AffinePoint<SmallRingElement>,
+    q: AffinePoint<SmallRingElement>,
+    /// Order of `P` or a multiple of it
+    order: u64,
+}
+
+impl EcdlpTask {
+    pub fn new(
+        p: AffinePoint<SmallRingElement>,
+        q: AffinePoint<SmallRingElement>,
+        order: u64,
+    ) -> Self {
+        Self { p, q, order }
+    }
+}
+
+impl Task for EcdlpTask {
+    fn template(&self) -> &str {
+        "ecdlp"
+    }
+
+    fn params(&self) -> BTreeMap<String, String> {
+        let curve = self.p.curve();
+        BTreeMap::from([
+            ("a".to_string(), curve.a().to_string()),
+            ("b".to_string(), curve.b().to_string()),
+            ("module".to_string(), curve.a().module().to_string()),
+            ("p".to_string(), self.p.to_string()),
+            ("q".to_string(), self.q.to_string()),
+            ("order".to_string(), self.order.to_string()),
+        ])
+    }
+
+    fn answer(&self) -> Result<String, ExamError> {
+        let (k, view) = baby_step_giant_step_with_view(&self.p, &self.q, self.order);
+        let k = k.ok_or_else(|| {
+            ExamError::InvalidTask(format!("{} is not a multiple of {}", self.q, self.p))
+        })?;
+        Ok(format!("$k = {k}$\n\n```\n{view}```\n"))
+    }
+}
+
+/// Tasks of a student's exam variant, reproducible from `seed` and `student_id`
+pub fn student_variant(seed: u64, student_id: u64) -> Result<Vec<Box<dyn Task>>, ExamError> {
+    let mut rng = SeededRng::for_task(seed, student_id, 1);
+    let (congruences, _) = crt_params(&mut rng, &CrtConstraints::default())?;
+    let mut rng = SeededRng::for_task(seed, student_id, 2);
+    let (x, y) = euclid_params(&mut rng, 20..=500, true)?;
+    let mut rng = SeededRng::for_task(seed, student_id, 3);
+    let element = inverse_params(&mut rng, 11..=101)?;
+    let mut rng = SeededRng::for_task(seed, student_id, 4);
+    let (shares, _) = shamir_params(&mut rng, 11..=97, 3)?;
+    let mut rng = SeededRng::for_task(seed, student_id, 5);
+    let (p, q, order, _) = ecdlp_params(&mut rng, 11..=101)?;
+    Ok(vec![
+        Box::new(CrtTask::new(congruences)),
+        Box::new(EuclidTask::new(x, y)),
+        Box::new(InverseTask::new(element)),
+        Box::new(ShamirTask::new(shares)),
+        Box::new(EcdlpTask::new(p, q, order)),
+    ])
+}
@@ -0,0 +1,353 @@
+//! Extension fields `F[x] / (f)` of degree `K` over a base field `F`
+
+use std::{
+    cmp,
+    hash::Hash,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::{
+    factor::factorize,
+    field::Field,
+    poly::Polynomial,
+    quadratic::tonelli_shanks_with,
+    ring::{Ring, RingElement},
+    units::element_order,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtensionError {
+    /// Module polynomial must have degree `K >= 1`
+    InvalidDegree {
+        expected: usize,
+        found: Option<usize>,
+    },
+    /// Module polynomial factors over the base field, so the quotient is not a field
+    Reducible,
+}
+
+impl std::fmt::Display for ExtensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExtensionError::InvalidDegree { expected, found } => match found {
+                Some(found) => write!(f, "Expected module of degree {expected}, got {found}"),
+                None => write!(f, "Expected module of degree {expected}, got zero"),
+            },
+            ExtensionError::Reducible => f.write_str("Module polynomial is reducible"),
+        }
+    }
+}
+
+impl std::error::Error for ExtensionError {}
+
+/// Rabin's test: `f` of degree `n` over `F_q` is irreducible iff `x^(q^n) = x (mod f)` and
+/// `gcd(x^(q^(n/r)) - x, f) = 1` for every prime `r | n`.
+///
+/// The base field order must fit into `u64`.
+pub fn is_irreducible<E: Field>(f: &Polynomial<E>) -> bool {
+    let (Some(n), Some(lead)) = (f.degree(), f.leading_coefficient()) else {
+        return false;
+    };
+    if n <= 1 {
+        return n == 1;
+    }
+    let q = lead
+        .ring()
+        .cardinality()
+        .expect("Base field order should fit into u64");
+    let x = Polynomial::monomial(lead.ring().one(), 1);
+    let maximal_divisors: Vec<usize> = factorize(n as u64)
+        .primes()
+        .map(|r| n / r as usize)
+        .collect();
+    // x^(q^i) mod f
+    let mut frobenius = x.clone();
+    for i in 1..=n {
+        frobenius = frobenius.pow_mod(q, f).expect("Non-zero module");
+        if maximal_divisors.contains(&i) {
+            let gcd = (&frobenius - &x).gcd(f).expect("Field coefficients");
+            if gcd.degree() != Some(0) {
+                return false;
+            }
+        }
+    }
+    frobenius == x.div_rem(f).expect("Non-zero module").1
+}
+
+/// `F[x] / (f)` for a monic irreducible `f` of degree `K`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionField<E, const K: usize> {
+    /// Lower coefficients `[m_0, ..., m_(K-1)]` of `f = x^K + m_(K-1) x^(K-1) + ... + m_0`
+    module: [E; K],
+}
+
+impl<E: Field, const K: usize> ExtensionField<E, K> {
+    /// Extension by `module`, which is made monic and checked for irreducibility
+    pub fn new(module: &Polynomial<E>) -> Result<Self, ExtensionError> {
+        if K == 0 || module.degree() != Some(K) {
+            return Err(ExtensionError::InvalidDegree {
+                expected: K,
+                found: module.degree(),
+            });
+        }
+        if !is_irreducible(module) {
+            return Err(ExtensionError::Reducible);
+        }
+        let monic = module.monic().expect("Field coefficients");
+        let mut coefficients = [monic.coefficients()[0]; K];
+        coefficients.copy_from_slice(&monic.coefficients()[..K]);
+        Ok(Self {
+            module: coefficients,
+        })
+    }
+
+    /// Monic module polynomial `f`
+    pub fn module_polynomial(&self) -> Polynomial<E> {
+        let mut coefficients = self.module.to_vec();
+        coefficients.push(self.base_one());
+        Polynomial::new(coefficients)
+    }
+
+    /// Class of `x`, a root of the module polynomial
+    pub fn generator(&self) -> ExtensionFieldElement<E, K> {
+        self.from_polynomial(&Polynomial::monomial(self.base_one(), 1))
+    }
+
+    /// Embedding of the base field
+    pub fn from_base(&self, value: E) -> ExtensionFieldElement<E, K> {
+        self.from_polynomial(&Polynomial::constant(value))
+    }
+
+    /// Class of a polynomial, reduced by the module
+    pub fn from_polynomial(&self, poly: &Polynomial<E>) -> ExtensionFieldElement<E, K> {
+        let remainder = poly
+            .div_rem(&self.module_polynomial())
+            .expect("Monic module")
+            .1;
+        let mut coefficients = [self.base_zero(); K];
+        coefficients[..remainder.coefficients().len()].copy_from_slice(remainder.coefficients());
+        self.create_element(coefficients)
+    }
+
+    fn base_zero(&self) -> E {
+        self.module[0].ring().zero()
+    }
+
+    fn base_one(&self) -> E {
+        self.module[0].ring().one()
+    }
+
+    /// Element whose coefficients are the base `p` digits of `index` for the characteristic `p`
+    fn element_from_index(&self, mut index: u64) -> ExtensionFieldElement<E, K> {
+        let p = self.base_one().characteristic();
+        let mut coefficients = [self.base_zero(); K];
+        for c in coefficients.iter_mut() {
+            *c = self.base_one().mul_u64(index % p);
+            index /= p;
+        }
+        self.create_element(coefficients)
+    }
+}
+
+impl<E: Field, const K: usize> std::fmt::Display for ExtensionField<E, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Extension field (mod {})", self.module_polynomial())
+    }
+}
+
+impl<E: Field, const K: usize> Ring for ExtensionField<E, K> {
+    type Element = ExtensionFieldElement<E, K>;
+    type Module = [E; K];
+    /// Coefficients `[a_0, ..., a_(K-1)]` of `a_0 + a_1 x + ... + a_(K-1) x^(K-1)`
+    type Value = [E; K];
+
+    fn create_element(&self, value: Self::Value) -> Self::Element {
+        ExtensionFieldElement {
+            field: *self,
+            coefficients: value,
+        }
+    }
+
+    fn module(&self) -> &Self::Module {
+        &self.module
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.create_element([self.base_zero(); K])
+    }
+
+    fn one(&self) -> Self::Element {
+        self.from_base(self.base_one())
+    }
+
+    /// `q^K` for the base field order `q`, `None` if it doesn't fit into `u64`
+    fn cardinality(&self) -> Option<u64> {
+        self.module[0].ring().cardinality()?.checked_pow(K as u32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionFieldElement<E, const K: usize> {
+    field: ExtensionField<E, K>,
+    coefficients: [E; K],
+}
+
+impl<E: Field, const K: usize> ExtensionFieldElement<E, K> {
+    pub fn coefficients(&self) -> &[E; K] {
+        &self.coefficients
+    }
+
+    pub fn to_polynomial(&self) -> Polynomial<E> {
+        Polynomial::new(self.coefficients.to_vec())
+    }
+
+    fn check_field(&self, rhs: &Self) {
+        if self.field != rhs.field {
+            panic!(
+                "Field operation failed, lhs field: {}, rhs field: {}",
+                self.field, rhs.field
+            );
+        }
+    }
+}
+
+impl<E: Field, const K: usize> Add for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        let mut coefficients = self.coefficients;
+        for (c, r) in coefficients.iter_mut().zip(rhs.coefficients) {
+            *c = *c + r;
+        }
+        self.field.create_element(coefficients)
+    }
+}
+
+impl<E: Field, const K: usize> Sub for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// Schoolbook product reduced by `x^K = -(m_(K-1) x^(K-1) + ... + m_0)`
+impl<E: Field, const K: usize> Mul for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        let zero = self.field.base_zero();
+        let mut product = vec![zero; 2 * K - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + *a * *b;
+            }
+        }
+        for i in (K..2 * K - 1).rev() {
+            let c = product[i];
+            for (j, m) in self.field.module.iter().enumerate() {
+                product[i - K + j] = product[i - K + j] - c * *m;
+            }
+        }
+        let mut coefficients = [zero; K];
+        coefficients.copy_from_slice(&product[..K]);
+        self.field.create_element(coefficients)
+    }
+}
+
+impl<E: Field, const K: usize> Div for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Remainder of the representative polynomials
+impl<E: Field, const K: usize> Rem for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        let (_, remainder) = self
+            .to_polynomial()
+            .div_rem(&rhs.to_polynomial())
+            .unwrap_or_else(|e| panic!("Field operation failed: {e}"));
+        self.field.from_polynomial(&remainder)
+    }
+}
+
+impl<E: Field, const K: usize> Neg for ExtensionFieldElement<E, K> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.field.create_element(self.coefficients.map(|c| -c))
+    }
+}
+
+impl<E: Field, const K: usize> PartialOrd for ExtensionFieldElement<E, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares coefficients starting from the highest power
+impl<E: Field, const K: usize> Ord for ExtensionFieldElement<E, K> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.coefficients
+            .iter()
+            .rev()
+            .cmp(other.coefficients.iter().rev())
+    }
+}
+
+impl<E: Field, const K: usize> std::fmt::Display for ExtensionFieldElement<E, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_polynomial().fmt(f)
+    }
+}
+
+impl<E: Field, const K: usize> RingElement for ExtensionFieldElement<E, K> {
+    type Ring = ExtensionField<E, K>;
+
+    fn ring(&self) -> &Self::Ring {
+        &self.field
+    }
+
+    /// `s` from `s * a + t * f = 1`
+    fn inverse(&self) -> Option<Self> {
+        let (gcd, s, _) = self
+            .to_polynomial()
+            .extended_gcd(&self.field.module_polynomial())
+            .ok()?;
+        (gcd.degree() == Some(0)).then(|| self.field.from_polynomial(&s))
+    }
+
+    /// The unit group of a finite field is cyclic of order `q^K - 1`
+    fn multiplicative_order(&self) -> Option<u64> {
+        let q = self.field.cardinality()?;
+        self.inverse()?;
+        Some(element_order(self, q - 1))
+    }
+}
+
+impl<E: Field, const K: usize> Field for ExtensionFieldElement<E, K> {
+    fn inv(&self) -> Self {
+        self.inverse()
+            .unwrap_or_else(|| panic!("Inverse of zero in {}", self.field))
+    }
+
+    fn characteristic(&self) -> u64 {
+        self.field.base_zero().characteristic()
+    }
+
+    /// Tonelli-Shanks with a non-residue searched among all field elements, the prime
+    /// subfield consists of squares when `K` is even
+    fn sqrt(&self) -> Option<Self> {
+        let q = self.field.cardinality()?;
+        if q.is_multiple_of(2) {
+            return Some(self.pow(q / 2));
+        }
+        let non_residue = (1..q)
+            .map(|i| self.field.element_from_index(i))
+            .find(|z| z.legendre() == -1)?;
+        tonelli_shanks_with(self, q, &non_residue)
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{integer::gcd, prime::is_prime};
+
+/// Trial division is used for factors below this bound
+const TRIAL_DIVISION_BOUND: u64 = 1000;
+/// Smoothness bound for Pollard's p - 1
+const P_MINUS_ONE_BOUND: u64 = 10_000;
+/// Pollard iterations kept in a view: the first ones and the last one
+const VIEW_ITERATIONS: usize = 6;
+
+/// Prime factorization `p_1^e_1 * ... * p_k^e_k` with `p_i` ascending
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Factorization {
+    factors: Vec<(u64, u32)>,
+}
+
+impl Factorization {
+    pub fn factors(&self) -> &[(u64, u32)] {
+        &self.factors
+    }
+
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.factors.iter().map(|(p, _)| *p)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.factors.iter().map(|(p, e)| p.pow(*e)).product()
+    }
+
+    fn push(&mut self, prime: u64, exponent: u32) {
+        match self.factors.iter_mut().find(|(p, _)| *p == prime) {
+            Some((_, e)) => *e += exponent,
+            None => {
+                self.factors.push((prime, exponent));
+                self.factors.sort_unstable();
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Factorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.factors.is_empty() {
+            return f.write_str("1");
+        }
+        for (i, (p, e)) in self.factors.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" * ")?;
+            }
+            if *e == 1 {
+                write!(f, "{p}")?;
+            } else {
+                write!(f, "{p}^{e}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FactorStep {
+    /// `divisor^exponent` divides the number
+    TrialDivision { divisor: u64, exponent: u32 },
+    /// Remaining cofactor is prime
+    Prime(u64),
+    /// Pollard's rho with `f(x) = x^2 + c`, rows `[x, y, gcd(|x - y|, n)]`
+    Rho {
+        n: u64,
+        c: u64,
+        iterations: Iterations,
+        factor: Option<u64>,
+    },
+    /// Pollard's p - 1 with base 2, rows `[k, a = 2^(k!), gcd(a - 1, n)]`
+    PMinusOne {
+        n: u64,
+        bound: u64,
+        iterations: Iterations,
+        factor: Option<u64>,
+    },
+}
+
+/// First `VIEW_ITERATIONS - 1` rows of a Pollard method and its last row, so the view stays
+/// short when the method runs for thousands of iterations
+#[derive(Debug, Clone, Default)]
+pub struct Iterations {
+    rows: Vec<[u64; 3]>,
+    count: usize,
+}
+
+impl Iterations {
+    pub fn rows(&self) -> &[[u64; 3]] {
+        &self.rows
+    }
+
+    /// Number of iterations performed, including the skipped ones
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn push(&mut self, row: [u64; 3]) {
+        self.count += 1;
+        if self.rows.len() == VIEW_ITERATIONS {
+            self.rows[VIEW_ITERATIONS - 1] = row;
+        } else {
+            self.rows.push(row);
+        }
+    }
+
+    fn fmt_rows(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        row: impl Fn(&mut std::fmt::Formatter<'_>, &[u64; 3]) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let skipped = self.count - self.rows.len();
+        for (i, iteration) in self.rows.iter().enumerate() {
+            if skipped > 0 && i + 1 == self.rows.len() {
+                writeln!(f, "  ... {skipped} iterations skipped")?;
+            }
+            row(f, iteration)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FactorView {
+    steps: Vec<FactorStep>,
+}
+
+impl FactorView {
+    pub fn steps(&self) -> &[FactorStep] {
+        &self.steps
+    }
+}
+
+impl std::fmt::Display for FactorView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for step in &self.steps {
+            match step {
+                FactorStep::TrialDivision { divisor, exponent } => {
+                    writeln!(f, "trial division: {divisor}^{exponent}")?
+                }
+                FactorStep::Prime(p) => writeln!(f, "{p} is prime")?,
+                FactorStep::Rho {
+                    n,
+                    c,
+                    iterations,
+                    factor,
+                } => {
+                    writeln!(f, "Pollard rho for n = {n}, f(x) = x^2 + {c}:")?;
+                    iterations.fmt_rows(f, |f, [x, y, d]| {
+                        writeln!(f, "  x = {x}, y = {y}, gcd(|x - y|, n) = {d}")
+                    })?;
+                    match factor {
+                        Some(d) => writeln!(f, "  {n} = {d} * {}", n / d)?,
+                        None => writeln!(f, "  failed")?,
+                    }
+                }
+                FactorStep::PMinusOne {
+                    n,
+                    bound,
+                    iterations,
+                    factor,
+                } => {
+                    writeln!(f, "Pollard p - 1 for n = {n}, B = {bound}:")?;
+                    iterations.fmt_rows(f, |f, [k, a, d]| {
+                        writeln!(f, "  k = {k}, a = {a}, gcd(a - 1, n) = {d}")
+                    })?;
+                    match factor {
+                        Some(d) => writeln!(f, "  {n} = {d} * {}", n / d)?,
+                        None => writeln!(f, "  failed")?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn factorize(n: u64) -> Factorization {
+    factorize_inner(n, None)
+}
+
+/// Same as `factorize`, but records trial division and the Pollard iterations
+pub fn factorize_with_view(n: u64) -> (Factorization, FactorView) {
+    let mut view = FactorView::default();
+    let factorization = factorize_inner(n, Some(&mut view));
+    (factorization, view)
+}
+
+/// Trial division by `2..bound`, returns found factors and the remaining cofactor
+pub fn trial_division(mut n: u64, bound: u64) -> (Factorization, u64) {
+    let mut factorization = Factorization::default();
+    let mut d = 2;
+    while d < bound && d <= n / d {
+        let mut exponent = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factorization.push(d, exponent);
+        }
+        d += 1;
+    }
+    (factorization, n)
+}
+
+/// Pollard's rho with Floyd cycle detection, returns a non-trivial factor of a composite `n`
+pub fn pollard_rho(n: u64, c: u64) -> Option<u64> {
+    rho(n, c, None)
+}
+
+/// Pollard's p - 1, finds a factor `p` if `p - 1` is `bound`-smooth
+pub fn pollard_p_minus_one(n: u64, bound: u64) -> Option<u64> {
+    p_minus_one(n, bound, None)
+}
+
+fn factorize_inner(n: u64, mut view: Option<&mut FactorView>) -> Factorization {
+    if n == 0 {
+        panic!("Zero has no prime factorization");
+    }
+    let (mut factorization, rest) = trial_division(n, TRIAL_DIVISION_BOUND);
+    if let Some(view) = view.as_deref_mut() {
+        for (divisor, exponent) in factorization.factors() {
+            view.steps.push(FactorStep::TrialDivision {
+                divisor: *divisor,
+                exponent: *exponent,
+            });
+        }
+    }
+    let mut stack = vec![rest];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            if let Some(view) = view.as_deref_mut() {
+                view.steps.push(FactorStep::Prime(m));
+            }
+            factorization.push(m, 1);
+            continue;
+        }
+        let d = split(m, view.as_deref_mut());
+        stack.push(d);
+        stack.push(m / d);
+    }
+    factorization
+}
+
+/// Finds a non-trivial factor of a composite `n`
+fn split(n: u64, mut view: Option<&mut FactorView>) -> u64 {
+    let mut iterations = view.as_ref().map(|_| Iterations::default());
+    let factor = p_minus_one(n, P_MINUS_ONE_BOUND, iterations.as_mut());
+    if let (Some(view), Some(iterations)) = (view.as_deref_mut(), iterations) {
+        view.steps.push(FactorStep::PMinusOne {
+            n,
+            bound: P_MINUS_ONE_BOUND,
+            iterations,
+            factor,
+        });
+    }
+    if let Some(d) = factor {
+        return d;
+    }
+    for c in 1.. {
+        let mut iterations = view.as_ref().map(|_| Iterations::default());
+        let factor = rho(n, c, iterations.as_mut());
+        if let (Some(view), Some(iterations)) = (view.as_deref_mut(), iterations) {
+            view.steps.push(FactorStep::Rho {
+                n,
+                c,
+                iterations,
+                factor,
+            });
+        }
+        if let Some(d) = factor {
+            return d;
+        }
+    }
+    unreachable!("Rho eventually splits a composite number")
+}
+
+fn rho(n: u64, c: u64, mut trace: Option<&mut Iterations>) -> Option<u64> {
+    if n.is_multiple_of(2) {
+        return (n > 2).then_some(2);
+    }
+    let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+    let (mut x, mut y) = (2, 2);
+    loop {
+        x = f(x);
+        y = f(f(y));
+        let d = gcd(x.abs_diff(y), n);
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push([x, y, d]);
+        }
+        if d == n {
+            return None;
+        }
+        if d > 1 {
+            return Some(d);
+        }
+    }
+}
+
+fn p_minus_one(n: u64, bound: u64, mut trace: Option<&mut Iterations>) -> Option<u64> {
+    if n < 2 {
+        return None;
+    }
+    let mut a = 2 % n;
+    for k in 2..=bound {
+        a = pow_mod(a, k, n);
+        let d = gcd(if a == 0 { n - 1 } else { a - 1 }, n);
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push([k, a, d]);
+        }
+        if d == n {
+            return None;
+        }
+        if d > 1 {
+            return Some(d);
+        }
+    }
+    None
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, module: u64) -> u64 {
+    let mut result = 1 % module;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result as u128 * base as u128 % module as u128) as u64;
+        }
+        base = (base as u128 * base as u128 % module as u128) as u64;
+        exp >>= 1;
+    }
+    result
+}
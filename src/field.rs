@@ -0,0 +1,250 @@
+use std::{
+    cmp,
+    hash::Hash,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use crate::{
+    ntt,
+    prime::is_prime,
+    quadratic::{field_legendre, tonelli_shanks},
+    ring::{Ring, RingElement, RingError, SmallRing, SmallRingElement},
+};
+
+/// Element of a field: every non-zero element is invertible
+pub trait Field: RingElement + Div<Output = Self> {
+    /// Inverse of a non-zero element, panics on zero
+    fn inv(&self) -> Self;
+    fn characteristic(&self) -> u64;
+
+    /// 1 for non-zero squares, -1 for non-squares and 0 for zero.
+    ///
+    /// Panics if the field order doesn't fit into `u64`.
+    fn legendre(&self) -> i8 {
+        let q = self
+            .ring()
+            .cardinality()
+            .expect("Field order should fit into u64");
+        if q.is_multiple_of(2) {
+            // Squaring is a bijection in characteristic 2
+            return i8::from(*self != self.ring().zero());
+        }
+        field_legendre(self, q)
+    }
+
+    /// Square root by Tonelli-Shanks, `None` for non-squares
+    fn sqrt(&self) -> Option<Self> {
+        let q = self.ring().cardinality()?;
+        if q.is_multiple_of(2) {
+            // Inverse of the Frobenius map x -> x^2
+            return Some(self.pow(q / 2));
+        }
+        tonelli_shanks(self, q)
+    }
+}
+
+/// Prime field `Z/pZ` for `p < 2^32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimeField {
+    module: u32,
+}
+
+impl PrimeField {
+    pub fn new(module: u32) -> Result<Self, RingError> {
+        if module < 2 {
+            return Err(RingError::InvalidModule(module));
+        }
+        if !is_prime(module as u64) {
+            return Err(RingError::NotPrime(module as u64));
+        }
+        Ok(Self { module })
+    }
+
+    pub fn from_ring(ring: SmallRing) -> Result<Self, RingError> {
+        Self::new(*ring.module())
+    }
+
+    /// Same field viewed as a general ring
+    pub fn ring(&self) -> SmallRing {
+        SmallRing::new(self.module).expect("Infallible")
+    }
+}
+
+impl std::fmt::Display for PrimeField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Field (mod ")?;
+        self.module.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+impl Ring for PrimeField {
+    type Element = PrimeFieldElement;
+    type Module = u32;
+    type Value = u64;
+
+    /// Creates an element reducing `value` by the field module
+    fn create_element(&self, value: Self::Value) -> Self::Element {
+        PrimeFieldElement {
+            field: *self,
+            value: value % self.module as u64,
+        }
+    }
+
+    fn module(&self) -> &Self::Module {
+        &self.module
+    }
+
+    fn zero(&self) -> Self::Element {
+        self.create_element(0)
+    }
+
+    fn one(&self) -> Self::Element {
+        self.create_element(1)
+    }
+
+    fn cardinality(&self) -> Option<u64> {
+        Some(self.module as u64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimeFieldElement {
+    field: PrimeField,
+    value: u64,
+}
+
+impl PrimeFieldElement {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn module(&self) -> u32 {
+        self.field.module
+    }
+
+    fn check_field(&self, rhs: &Self) {
+        if self.field != rhs.field {
+            panic!(
+                "Field operation failed, lhs field: {}, rhs field: {}",
+                self.field, rhs.field
+            );
+        }
+    }
+}
+
+/// Fails if the ring module is not prime
+impl TryFrom<SmallRingElement> for PrimeFieldElement {
+    type Error = RingError;
+
+    fn try_from(element: SmallRingElement) -> Result<Self, Self::Error> {
+        Ok(PrimeField::new(element.module())?.create_element(element.value()))
+    }
+}
+
+impl From<PrimeFieldElement> for SmallRingElement {
+    fn from(element: PrimeFieldElement) -> Self {
+        element.field.ring().create_element(element.value)
+    }
+}
+
+impl Add for PrimeFieldElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        self.field.create_element(self.value + rhs.value)
+    }
+}
+
+impl Sub for PrimeFieldElement {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        self.field
+            .create_element(self.value + self.field.module as u64 - rhs.value)
+    }
+}
+
+impl Mul for PrimeFieldElement {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        self.field.create_element(self.value * rhs.value)
+    }
+}
+
+impl Div for PrimeFieldElement {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+impl Rem for PrimeFieldElement {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.check_field(&rhs);
+        if rhs.value == 0 {
+            panic!("Field operation failed: {}", RingError::DivisionByZero);
+        }
+        self.field.create_element(self.value % rhs.value)
+    }
+}
+
+impl Neg for PrimeFieldElement {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.field
+            .create_element(self.field.module as u64 - self.value)
+    }
+}
+
+impl PartialOrd for PrimeFieldElement {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrimeFieldElement {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl std::fmt::Display for PrimeFieldElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl RingElement for PrimeFieldElement {
+    type Ring = PrimeField;
+
+    fn ring(&self) -> &Self::Ring {
+        &self.field
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        (self.value != 0).then(|| self.inv())
+    }
+
+    fn root_of_unity(&self, log_n: u32) -> Option<Self> {
+        let root = ntt::root_of_unity(&self.field.ring(), log_n)?;
+        Some(self.field.create_element(root.value()))
+    }
+}
+
+impl Field for PrimeFieldElement {
+    /// Fermat's little theorem: `a^(p - 2) = a^-1`
+    fn inv(&self) -> Self {
+        if self.value == 0 {
+            panic!("Inverse of zero in {}", self.field);
+        }
+        self.pow(self.field.module - 2)
+    }
+
+    fn characteristic(&self) -> u64 {
+        self.field.module as u64
+    }
+}
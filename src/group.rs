@@ -0,0 +1,180 @@
+//! Abstract groups written multiplicatively, e.g. units or residues of a ring and points of a
+//! curve, so that protocols can be written once for any of them
+
+use std::hash::Hash;
+
+use crate::{
+    curve::AffinePoint,
+    curve_group::{count_points, point_order},
+    factor::factorize,
+    ring::{Exponent, Ring, RingElement},
+    units::order_dividing,
+};
+
+pub trait Group:
+    PartialEq + Eq + Hash + std::fmt::Debug + std::fmt::Display + Clone + Copy + Send + Sync + 'static
+{
+    fn identity(&self) -> Self;
+    /// Group operation, `self * rhs` or `self + rhs` for additive groups
+    fn operate(&self, rhs: &Self) -> Self;
+    fn inverse(&self) -> Self;
+    /// Order of the element, `None` if it's not known, e.g. the group is infinite or too large
+    /// for `u64`
+    fn order(&self) -> Option<u64>;
+
+    fn is_identity(&self) -> bool {
+        *self == self.identity()
+    }
+
+    /// `self^k` (`k * self` for additive groups) by left-to-right square-and-multiply
+    fn pow(&self, k: impl Exponent) -> Self {
+        let mut result = self.identity();
+        for i in (0..k.bits()).rev() {
+            result = result.operate(&result);
+            if k.bit(i) {
+                result = result.operate(self);
+            }
+        }
+        result
+    }
+}
+
+/// Group with a commutative operation
+pub trait AbelianGroup: Group {}
+
+/// Element of a ring as an element of its additive group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Additive<E> {
+    element: E,
+}
+
+impl<E: RingElement> Additive<E> {
+    pub fn new(element: E) -> Self {
+        Self { element }
+    }
+
+    pub fn element(&self) -> E {
+        self.element
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for Additive<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.element)
+    }
+}
+
+impl<E: RingElement> Group for Additive<E> {
+    fn identity(&self) -> Self {
+        Self {
+            element: self.element.ring().zero(),
+        }
+    }
+
+    fn operate(&self, rhs: &Self) -> Self {
+        Self {
+            element: self.element + rhs.element,
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        Self {
+            element: -self.element,
+        }
+    }
+
+    /// Smallest divisor `k` of the ring size with `k * element = 0`
+    fn order(&self) -> Option<u64> {
+        let n = self.element.ring().cardinality()?;
+        Some(order_dividing(n, &factorize(n), |k| {
+            self.element.mul_u64(k).is_zero()
+        }))
+    }
+}
+
+impl<E: RingElement> AbelianGroup for Additive<E> {}
+
+/// Unit of a ring as an element of its multiplicative group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Multiplicative<E> {
+    element: E,
+}
+
+impl<E: RingElement> Multiplicative<E> {
+    /// `None` for non-units
+    pub fn new(element: E) -> Option<Self> {
+        element.inverse()?;
+        Some(Self { element })
+    }
+
+    pub fn element(&self) -> E {
+        self.element
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for Multiplicative<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.element)
+    }
+}
+
+impl<E: RingElement> Group for Multiplicative<E> {
+    fn identity(&self) -> Self {
+        Self {
+            element: self.element.ring().one(),
+        }
+    }
+
+    fn operate(&self, rhs: &Self) -> Self {
+        Self {
+            element: self.element * rhs.element,
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        Self {
+            element: self.element.inverse().expect("Units are invertible"),
+        }
+    }
+
+    /// `None` if the ring size doesn't fit into `u64`
+    fn order(&self) -> Option<u64> {
+        self.element.multiplicative_order()
+    }
+
+    fn pow(&self, k: impl Exponent) -> Self {
+        Self {
+            element: self.element.pow(k),
+        }
+    }
+}
+
+impl<E: RingElement> AbelianGroup for Multiplicative<E> {}
+
+/// Points of a curve under addition
+impl<E: RingElement> Group for AffinePoint<E> {
+    fn identity(&self) -> Self {
+        self.curve().infinity()
+    }
+
+    fn operate(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn inverse(&self) -> Self {
+        -*self
+    }
+
+    /// Divisor of `#E(F_p)` found by counting points, `None` unless the coefficients form a
+    /// prime field `F_p` with `p` fitting into `u64`
+    fn order(&self) -> Option<u64> {
+        Some(point_order(self, count_points(self.curve())?))
+    }
+
+    /// Double-and-add in Jacobian coordinates
+    fn pow(&self, k: impl Exponent) -> Self {
+        self.scalar_mul(k)
+    }
+}
+
+impl<E: RingElement> AbelianGroup for AffinePoint<E> {}
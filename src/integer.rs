@@ -0,0 +1,24 @@
+//! Integer helpers shared by the number theory modules
+
+pub(crate) fn gcd(mut x: u64, mut y: u64) -> u64 {
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    x
+}
+
+/// `a^-1 (mod m)` by the extended Euclidean algorithm, `None` if `gcd(a, m) != 1`
+pub(crate) fn inverse_mod(a: u64, m: u64) -> Option<u64> {
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    (r0 == 1).then(|| t0.rem_euclid(m as i128) as u64)
+}
+
+pub(crate) fn lcm(x: u64, y: u64) -> u64 {
+    x / gcd(x, y) * y
+}
@@ -0,0 +1,237 @@
+//! Polynomial interpolation through points `[x_i, y_i]` with distinct nodes `x_i`
+
+use crate::{
+    poly::Polynomial,
+    ring::{Ring, RingElement},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolationError {
+    NoPoints,
+    /// `x_i - x_j` has no inverse, e.g. the nodes coincide
+    NonInvertibleDifference {
+        i: usize,
+        j: usize,
+    },
+}
+
+impl std::fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InterpolationError::NoPoints => f.write_str("No points to interpolate"),
+            InterpolationError::NonInvertibleDifference { i, j } => {
+                write!(f, "Difference of nodes x_{i} - x_{j} is not invertible")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InterpolationError {}
+
+/// Lagrange basis polynomial `L_i(x) = prod_(j != i) (x - x_j) / (x_i - x_j)`
+#[derive(Debug, Clone)]
+pub struct LagrangeBasis<E> {
+    pub node: E,
+    pub value: E,
+    /// `prod_(j != i) (x - x_j)`
+    pub numerator: Polynomial<E>,
+    /// `prod_(j != i) (x_i - x_j)`
+    pub denominator: E,
+    pub denominator_inv: E,
+    pub basis: Polynomial<E>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LagrangeView<E> {
+    bases: Vec<LagrangeBasis<E>>,
+}
+
+impl<E> LagrangeView<E> {
+    pub fn bases(&self) -> &[LagrangeBasis<E>] {
+        &self.bases
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for LagrangeView<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, basis) in self.bases.iter().enumerate() {
+            writeln!(
+                f,
+                "L_{i}(x) = ({}) * {}^(-1) = ({}) * {} = {}",
+                basis.numerator,
+                basis.denominator,
+                basis.numerator,
+                basis.denominator_inv,
+                basis.basis
+            )?;
+        }
+        let terms: Vec<String> = self
+            .bases
+            .iter()
+            .enumerate()
+            .map(|(i, basis)| format!("{} * L_{i}(x)", basis.value))
+            .collect();
+        writeln!(f, "f(x) = {}", terms.join(" + "))
+    }
+}
+
+/// Lagrange interpolation, returns the polynomial of degree below the number of points
+pub fn lagrange<E: RingElement>(
+    points: &[[E; 2]],
+) -> Result<(Polynomial<E>, LagrangeView<E>), InterpolationError> {
+    let first = points.first().ok_or(InterpolationError::NoPoints)?;
+    let one = first[0].ring().one();
+    let mut result = Polynomial::zero();
+    let mut bases = vec![];
+    for (i, [x_i, y_i]) in points.iter().enumerate() {
+        let mut numerator = Polynomial::constant(one);
+        let mut denominator = one;
+        for (j, [x_j, _]) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            numerator = &numerator * &Polynomial::new(vec![-*x_j, one]);
+            denominator = denominator * (*x_i - *x_j);
+        }
+        let denominator_inv = denominator
+            .inverse()
+            .ok_or_else(|| non_invertible_pair(points, i))?;
+        let basis = numerator.scale(&denominator_inv);
+        result = &result + &basis.scale(y_i);
+        bases.push(LagrangeBasis {
+            node: *x_i,
+            value: *y_i,
+            numerator,
+            denominator,
+            denominator_inv,
+            basis,
+        });
+    }
+    Ok((result, LagrangeView { bases }))
+}
+
+/// Divided difference `f[x_from, ..., x_to] = (f[x_(from+1), ..., x_to] - f[x_from, ..., x_(to-1)])
+/// / (x_to - x_from)`
+#[derive(Debug, Clone)]
+pub struct DividedDifference<E> {
+    pub from: usize,
+    pub to: usize,
+    /// `x_to - x_from`
+    pub denominator: E,
+    pub denominator_inv: E,
+    pub value: E,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewtonView<E> {
+    /// `levels[k]` holds the differences of `k + 2` consecutive points
+    levels: Vec<Vec<DividedDifference<E>>>,
+    /// `f[x_0, ..., x_k]`
+    coefficients: Vec<E>,
+    /// `prod_(j < k) (x - x_j)`
+    bases: Vec<Polynomial<E>>,
+}
+
+impl<E> NewtonView<E> {
+    pub fn levels(&self) -> &[Vec<DividedDifference<E>>] {
+        &self.levels
+    }
+
+    pub fn coefficients(&self) -> &[E] {
+        &self.coefficients
+    }
+
+    pub fn bases(&self) -> &[Polynomial<E>] {
+        &self.bases
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for NewtonView<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for difference in self.levels.iter().flatten() {
+            let (from, to) = (difference.from, difference.to);
+            let (upper, lower) = (divided(from + 1, to), divided(from, to - 1));
+            writeln!(
+                f,
+                "{} = ({upper} - {lower}) * {}^(-1) = ({upper} - {lower}) * {} = {}",
+                divided(from, to),
+                difference.denominator,
+                difference.denominator_inv,
+                difference.value
+            )?;
+        }
+        for (k, basis) in self.bases.iter().enumerate() {
+            writeln!(f, "n_{k}(x) = {basis}")?;
+        }
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, c)| format!("{c} * n_{k}(x)"))
+            .collect();
+        writeln!(f, "f(x) = {}", terms.join(" + "))
+    }
+}
+
+fn divided(from: usize, to: usize) -> String {
+    if from == to {
+        format!("f[x_{from}]")
+    } else {
+        format!("f[x_{from}..x_{to}]")
+    }
+}
+
+/// Newton interpolation `f(x) = sum_k f[x_0, ..., x_k] * prod_(j < k) (x - x_j)`
+pub fn newton<E: RingElement>(
+    points: &[[E; 2]],
+) -> Result<(Polynomial<E>, NewtonView<E>), InterpolationError> {
+    let first = points.first().ok_or(InterpolationError::NoPoints)?;
+    let one = first[0].ring().one();
+    let mut current: Vec<E> = points.iter().map(|[_, y]| *y).collect();
+    let mut coefficients = vec![current[0]];
+    let mut levels = vec![];
+    for width in 1..points.len() {
+        let mut level = vec![];
+        for from in 0..points.len() - width {
+            let to = from + width;
+            let denominator = points[to][0] - points[from][0];
+            let denominator_inv = denominator
+                .inverse()
+                .ok_or(InterpolationError::NonInvertibleDifference { i: to, j: from })?;
+            let value = (current[from + 1] - current[from]) * denominator_inv;
+            level.push(DividedDifference {
+                from,
+                to,
+                denominator,
+                denominator_inv,
+                value,
+            });
+        }
+        current = level.iter().map(|d| d.value).collect();
+        coefficients.push(current[0]);
+        levels.push(level);
+    }
+    let mut bases = vec![Polynomial::constant(one)];
+    for [x, _] in &points[..points.len() - 1] {
+        let next = &bases[bases.len() - 1] * &Polynomial::new(vec![-*x, one]);
+        bases.push(next);
+    }
+    let result = coefficients
+        .iter()
+        .zip(&bases)
+        .fold(Polynomial::zero(), |acc, (c, basis)| &acc + &basis.scale(c));
+    let view = NewtonView {
+        levels,
+        coefficients,
+        bases,
+    };
+    Ok((result, view))
+}
+
+/// Finds the node `j` whose difference with `x_i` is not invertible
+fn non_invertible_pair<E: RingElement>(points: &[[E; 2]], i: usize) -> InterpolationError {
+    let j = (0..points.len())
+        .find(|j| *j != i && (points[i][0] - points[*j][0]).inverse().is_none())
+        .unwrap_or(i);
+    InterpolationError::NonInvertibleDifference { i, j }
+}
@@ -1 +1,22 @@
+pub mod bigint;
+pub mod binary;
+pub mod crt;
+pub mod curve;
+pub mod curve_group;
+pub mod dlog;
+pub mod exam;
+pub mod extension;
+pub mod factor;
+pub mod field;
+pub mod group;
+mod integer;
+pub mod interpolation;
+pub mod montgomery;
+pub mod ntt;
+pub mod params;
+pub mod poly;
+pub mod prime;
+pub mod quadratic;
 pub mod ring;
+pub mod shamir;
+pub mod units;
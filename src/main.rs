@@ -1,6 +1,31 @@
-use zk_exam::ring::extended_euclidean;
+use zk_exam::{
+    exam::{ExamGenerator, student_variant},
+    ring::extended_euclidean,
+};
+
+/// Usage: `zk-exam exam <seed> <students> [out_dir]`
+fn generate_exams(args: &[String]) {
+    let seed: u64 = args[0].parse().unwrap();
+    let students: u64 = args[1].parse().unwrap();
+    let out_dir = args.get(2).map(String::as_str).unwrap_or("exams");
+    let generator = ExamGenerator::from_dir("templates").unwrap();
+    for student_id in 1..=students {
+        let tasks = student_variant(seed, student_id).unwrap();
+        let exam = generator
+            .generate(&format!("Exam, variant {student_id}"), &tasks)
+            .unwrap();
+        exam.write(out_dir, &format!("variant_{student_id}"))
+            .unwrap();
+    }
+}
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("exam") {
+        generate_exams(&args[1..]);
+        return;
+    }
+
     let mut buf = String::new();
     std::io::stdin().read_line(&mut buf).unwrap();
     let x: i64 = buf.trim().parse().unwrap();
@@ -0,0 +1,139 @@
+//! Radix-2 number theoretic transform: evaluation of a polynomial at powers of a primitive
+//! `n`-th root of unity in `O(n log n)`
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::{
+    poly::Polynomial,
+    prime::is_prime,
+    ring::{Ring, RingElement, SmallRing, SmallRingElement},
+};
+
+/// Primitive `2^s`-th root of unity for `p - 1 = 2^s * t` with odd `t` by module, `None` for
+/// composite modules, so primality and the root search run once per module
+type TwoAdicRoots = Mutex<HashMap<u32, Option<(u32, u64)>>>;
+
+fn two_adic_roots() -> &'static TwoAdicRoots {
+    static ROOTS: OnceLock<TwoAdicRoots> = OnceLock::new();
+    ROOTS.get_or_init(Default::default)
+}
+
+/// Returns `(s, w)` with `w` a primitive `2^s`-th root of unity, `2^s` the largest power of two
+/// dividing `p - 1`
+fn two_adic_root(ring: &SmallRing) -> Option<(u32, u64)> {
+    let p = *ring.module();
+    let mut roots = two_adic_roots()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *roots.entry(p).or_insert_with(|| {
+        let p = p as u64;
+        if !is_prime(p) {
+            return None;
+        }
+        let s = (p - 1).trailing_zeros();
+        if s == 0 {
+            return Some((0, 1));
+        }
+        // x^((p-1)/2^s) has order exactly 2^s iff its 2^(s-1)-th power is -1, that is iff x is
+        // a quadratic non-residue
+        let minus_one = -ring.one();
+        (2..p)
+            .map(|x| ring.create_element(x).pow((p - 1) >> s))
+            .find(|w| w.pow(1u64 << (s - 1)) == minus_one)
+            .map(|w| (s, w.value()))
+    })
+}
+
+/// Primitive `2^log_n`-th root of unity in the prime field `Z/pZ`, `None` if `p` is not prime
+/// or `2^log_n` doesn't divide `p - 1`
+pub fn root_of_unity(ring: &SmallRing, log_n: u32) -> Option<SmallRingElement> {
+    let (s, w) = two_adic_root(ring)?;
+    if log_n > s {
+        return None;
+    }
+    Some(ring.create_element(w).pow(1u64 << (s - log_n)))
+}
+
+/// In-place forward transform: `values[i]` becomes `f(root^i)` for `f = sum values[j] x^j`.
+///
+/// `root` must be a primitive `n`-th root of unity for the power of two `n = values.len()`.
+pub fn ntt<E: RingElement>(values: &mut [E], root: E) {
+    let n = values.len();
+    if !n.is_power_of_two() {
+        panic!("NTT size must be a power of two, got {n}");
+    }
+    bit_reverse(values);
+    let mut len = 2;
+    while len <= n {
+        let w_len = root.pow((n / len) as u64);
+        for chunk in values.chunks_mut(len) {
+            let mut w = root.ring().one();
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = *b * w;
+                (*a, *b) = (*a + t, *a - t);
+                w = w * w_len;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// In-place inverse of `ntt` with the same `root`
+pub fn inverse_ntt<E: RingElement>(values: &mut [E], root: E) {
+    let root_inv = root.inverse().expect("Root of unity is a unit");
+    ntt(values, root_inv);
+    let n_inv = root
+        .ring()
+        .one()
+        .mul_u64(values.len() as u64)
+        .inverse()
+        .expect("Transform size should be invertible");
+    for value in values.iter_mut() {
+        *value = *value * n_inv;
+    }
+}
+
+/// Product of polynomials by NTT, `None` if the ring has no root of unity of the needed order
+pub fn multiply<E: RingElement>(a: &Polynomial<E>, b: &Polynomial<E>) -> Option<Polynomial<E>> {
+    let (Some(lead), Some(_)) = (a.leading_coefficient(), b.leading_coefficient()) else {
+        return Some(Polynomial::zero());
+    };
+    let len = a.coefficients().len() + b.coefficients().len() - 1;
+    let n = len.next_power_of_two();
+    let root = lead.root_of_unity(n.trailing_zeros())?;
+    let zero = lead.ring().zero();
+    let transform = |poly: &Polynomial<E>| {
+        let mut values = poly.coefficients().to_vec();
+        values.resize(n, zero);
+        ntt(&mut values, root);
+        values
+    };
+    let mut values: Vec<E> = transform(a)
+        .into_iter()
+        .zip(transform(b))
+        .map(|(x, y)| x * y)
+        .collect();
+    inverse_ntt(&mut values, root);
+    values.truncate(len);
+    Some(Polynomial::new(values))
+}
+
+fn bit_reverse<E>(values: &mut [E]) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    curve::AffinePoint,
+    curve_group::find_prime_order_curve,
+    integer::gcd,
+    prime::is_prime,
+    ring::{Ring, RingError, SmallRing, SmallRingElement},
+    shamir::{Share, split},
+};
+
+/// Attempts made to satisfy task constraints before giving up
+const MAX_ATTEMPTS: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParamsError {
+    EmptyRange,
+    /// No parameters satisfying constraints were found in `MAX_ATTEMPTS` attempts
+    Unsatisfiable(&'static str),
+    Ring(RingError),
+}
+
+impl std::fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamsError::EmptyRange => f.write_str("Empty parameter range"),
+            ParamsError::Unsatisfiable(what) => write!(f, "Can't satisfy constraints: {what}"),
+            ParamsError::Ring(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+impl From<RingError> for ParamsError {
+    fn from(e: RingError) -> Self {
+        ParamsError::Ring(e)
+    }
+}
+
+/// Deterministic SplitMix64 generator.
+///
+/// The output sequence is fixed for a given seed across platforms and versions, so every
+/// exam variant can be regenerated from `(seed, student_id, task_number)`.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Independent stream for a task of a student's variant
+    pub fn for_task(seed: u64, student_id: u64, task_number: u64) -> Self {
+        let mut rng = Self::new(seed);
+        for part in [student_id, task_number] {
+            rng.state ^= part;
+            rng.state = rng.next_u64();
+        }
+        rng
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `range` without modulo bias
+    pub fn gen_range(&mut self, range: RangeInclusive<u64>) -> Result<u64, ParamsError> {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return Err(ParamsError::EmptyRange);
+        }
+        let Some(size) = (end - start).checked_add(1) else {
+            return Ok(self.next_u64());
+        };
+        let zone = u64::MAX - u64::MAX % size;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return Ok(start + value % size);
+            }
+        }
+    }
+}
+
+/// Constraints for a CRT system
+#[derive(Debug, Clone)]
+pub struct CrtConstraints {
+    /// Number of congruences
+    pub count: usize,
+    /// Range of each module
+    pub modules: RangeInclusive<u64>,
+    /// Range of the answer, it must be smaller than the product of modules to be unique
+    pub answer: RangeInclusive<u64>,
+}
+
+impl Default for CrtConstraints {
+    fn default() -> Self {
+        Self {
+            count: 3,
+            modules: 3..=20,
+            answer: 0..=999,
+        }
+    }
+}
+
+/// Generates congruences `[r_i, m_i]` with pairwise coprime modules and returns them with the
+/// answer
+pub fn crt_params(
+    rng: &mut SeededRng,
+    constraints: &CrtConstraints,
+) -> Result<(Vec<[i64; 2]>, u64), ParamsError> {
+    for _ in 0..MAX_ATTEMPTS {
+        let mut modules: Vec<u64> = vec![];
+        // Start over after a few misses, the chosen modules may leave no coprime candidates
+        for _ in 0..constraints.count * 10 {
+            if modules.len() == constraints.count {
+                break;
+            }
+            let candidate = rng.gen_range(constraints.modules.clone())?;
+            if candidate > 1 && modules.iter().all(|m| gcd(*m, candidate) == 1) {
+                modules.push(candidate);
+            }
+        }
+        if modules.len() < constraints.count {
+            continue;
+        }
+        let product = modules
+            .iter()
+            .try_fold(1u64, |acc, m| acc.checked_mul(*m))
+            .filter(|product| *product <= i64::MAX as u64);
+        let Some(product) = product else {
+            continue;
+        };
+        if *constraints.answer.end() >= product {
+            continue;
+        }
+        let answer = rng.gen_range(constraints.answer.clone())?;
+        let congruences = modules
+            .iter()
+            .map(|m| [(answer % m) as i64, *m as i64])
+            .collect();
+        return Ok((congruences, answer));
+    }
+    Err(ParamsError::Unsatisfiable("pairwise coprime modules"))
+}
+
+/// Generates `(x, y)` for the extended Euclidean task, coprime when `coprime` is set
+pub fn euclid_params(
+    rng: &mut SeededRng,
+    range: RangeInclusive<u64>,
+    coprime: bool,
+) -> Result<(i64, i64), ParamsError> {
+    for _ in 0..MAX_ATTEMPTS {
+        let x = rng.gen_range(range.clone())?;
+        let y = rng.gen_range(range.clone())?;
+        if x < 2 || y < 2 || x == y || (coprime && gcd(x, y) != 1) {
+            continue;
+        }
+        return Ok((x as i64, y as i64));
+    }
+    Err(ParamsError::Unsatisfiable("distinct values greater than 1"))
+}
+
+/// Generates a ring with module in `modules` and its invertible element other than 1
+pub fn inverse_params(
+    rng: &mut SeededRng,
+    modules: RangeInclusive<u32>,
+) -> Result<SmallRingElement, ParamsError> {
+    let (start, end) = modules.into_inner();
+    for _ in 0..MAX_ATTEMPTS {
+        let module = rng.gen_range(start as u64..=end as u64)? as u32;
+        if module < 3 {
+            continue;
+        }
+        let value = rng.gen_range(2..=module as u64 - 1)?;
+        if gcd(value, module as u64) != 1 {
+            continue;
+        }
+        return Ok(SmallRing::new(module)?.create_element(value));
+    }
+    Err(ParamsError::Unsatisfiable("invertible element"))
+}
+
+/// Generates a secret in a prime field with module in `modules` and `threshold` of its shares
+/// picked at random among `threshold + 2`
+pub fn shamir_params(
+    rng: &mut SeededRng,
+    modules: RangeInclusive<u32>,
+    threshold: usize,
+) -> Result<(Vec<Share<SmallRingElement>>, SmallRingElement), ParamsError> {
+    let count = threshold + 2;
+    let (start, end) = modules.into_inner();
+    for _ in 0..MAX_ATTEMPTS {
+        let module = rng.gen_range(start as u64..=end as u64)?;
+        if module <= count as u64 || !is_prime(module) {
+            continue;
+        }
+        let ring = SmallRing::new(module as u32)?;
+        let secret = ring.create_element(rng.gen_range(0..=module - 1)?);
+        let (mut shares, _) = split(&secret, threshold, count, rng)
+            .map_err(|_| ParamsError::Unsatisfiable("positive threshold"))?;
+        while shares.len() > threshold {
+            let index = rng.gen_range(0..=shares.len() as u64 - 1)?;
+            shares.remove(index as usize);
+        }
+        return Ok((shares, secret));
+    }
+    Err(ParamsError::Unsatisfiable(
+        "prime module above the share count",
+    ))
+}
+
+/// Generates points `P` and `Q = k * P` on a curve of prime order over a prime field with module
+/// in `modules`, returns them with the curve order and `k`
+pub fn ecdlp_params(
+    rng: &mut SeededRng,
+    modules: RangeInclusive<u32>,
+) -> Result<
+    (
+        AffinePoint<SmallRingElement>,
+        AffinePoint<SmallRingElement>,
+        u64,
+        u64,
+    ),
+    ParamsError,
+> {
+    let (start, end) = modules.into_inner();
+    for _ in 0..MAX_ATTEMPTS {
+        let module = rng.gen_range(start as u64..=end as u64)?;
+        if module <= 3 || !is_prime(module) {
+            continue;
+        }
+        let ring = SmallRing::new(module as u32)?;
+        let Some((curve, order)) = find_prime_order_curve(&ring, rng) else {
+            continue;
+        };
+        // Hasse's bound allows orders 2 and 3 over tiny fields, which leave no room for k
+        if order < 4 {
+            continue;
+        }
+        // Every finite point generates a group of prime order
+        let x = ring.create_element(rng.gen_range(0..=module - 1)?);
+        let Some(y) = curve.rhs(&x).sqrt() else {
+            continue;
+        };
+        let p = curve.point(x, y).expect("y^2 = rhs");
+        let k = rng.gen_range(2..=order - 1)?;
+        return Ok((p, p.scalar_mul(k), order, k));
+    }
+    Err(ParamsError::Unsatisfiable("curve of prime order"))
+}
@@ -0,0 +1,321 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    ntt,
+    ring::{Ring, RingElement},
+};
+
+/// Multiplication switches to the NTT when both factors have at least this many coefficients
+const NTT_THRESHOLD: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PolyError {
+    DivisionByZero,
+    /// Division needs an invertible leading coefficient of the divisor
+    NonInvertibleLeadingCoefficient,
+}
+
+impl std::fmt::Display for PolyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolyError::DivisionByZero => f.write_str("Division by zero polynomial"),
+            PolyError::NonInvertibleLeadingCoefficient => {
+                f.write_str("Leading coefficient of the divisor is not invertible")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolyError {}
+
+/// Polynomial over a ring, coefficients are stored from the constant term up without trailing
+/// zeros, so the zero polynomial has no coefficients
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<E> {
+    coefficients: Vec<E>,
+}
+
+impl<E: RingElement> Polynomial<E> {
+    /// Creates a polynomial from coefficients `[a_0, a_1, ...]` of `a_0 + a_1 x + ...`
+    pub fn new(coefficients: Vec<E>) -> Self {
+        let mut poly = Self { coefficients };
+        poly.trim();
+        poly
+    }
+
+    pub fn zero() -> Self {
+        Self {
+            coefficients: vec![],
+        }
+    }
+
+    pub fn constant(c: E) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// c * x^degree
+    pub fn monomial(c: E, degree: usize) -> Self {
+        let mut coefficients = vec![c.ring().zero(); degree + 1];
+        coefficients[degree] = c;
+        Self::new(coefficients)
+    }
+
+    pub fn coefficients(&self) -> &[E] {
+        &self.coefficients
+    }
+
+    /// Coefficient of `x^i`, `None` above the degree
+    pub fn coefficient(&self, i: usize) -> Option<&E> {
+        self.coefficients.get(i)
+    }
+
+    /// `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<&E> {
+        self.coefficients.last()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            self.coefficients.pop();
+        }
+    }
+
+    /// Horner's scheme
+    pub fn evaluate(&self, x: &E) -> E {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(x.ring().zero(), |acc, c| acc * *x + *c)
+    }
+
+    pub fn scale(&self, c: &E) -> Self {
+        Self::new(self.coefficients.iter().map(|a| *a * *c).collect())
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c.mul_u64(i as u64))
+                .collect(),
+        )
+    }
+
+    /// Long division `self = q * divisor + r` with `deg r < deg divisor`
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), PolyError> {
+        let divisor_degree = divisor.degree().ok_or(PolyError::DivisionByZero)?;
+        let lead_inv = divisor.coefficients[divisor_degree]
+            .inverse()
+            .ok_or(PolyError::NonInvertibleLeadingCoefficient)?;
+        let mut remainder = self.clone();
+        let Some(degree) = self.degree().filter(|d| *d >= divisor_degree) else {
+            return Ok((Self::zero(), remainder));
+        };
+        let zero = lead_inv.ring().zero();
+        let mut quotient = vec![zero; degree - divisor_degree + 1];
+        while let Some(r_degree) = remainder.degree().filter(|d| *d >= divisor_degree) {
+            let shift = r_degree - divisor_degree;
+            let factor = remainder.coefficients[r_degree] * lead_inv;
+            quotient[shift] = factor;
+            for (i, c) in divisor.coefficients.iter().enumerate() {
+                let r = &mut remainder.coefficients[shift + i];
+                *r = *r - factor * *c;
+            }
+            // Leading term cancels even if the ring has zero divisors
+            remainder.coefficients[r_degree] = zero;
+            remainder.trim();
+        }
+        Ok((Self::new(quotient), remainder))
+    }
+
+    /// Scales to leading coefficient 1
+    pub fn monic(&self) -> Result<Self, PolyError> {
+        let Some(lead) = self.leading_coefficient() else {
+            return Ok(Self::zero());
+        };
+        let lead_inv = lead
+            .inverse()
+            .ok_or(PolyError::NonInvertibleLeadingCoefficient)?;
+        Ok(self.scale(&lead_inv))
+    }
+
+    /// Monic gcd by the Euclidean algorithm, all remainders must have invertible leading
+    /// coefficients, which always holds over a field
+    pub fn gcd(&self, other: &Self) -> Result<Self, PolyError> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b)?.1;
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+
+    /// Extended Euclidean algorithm: monic `g = gcd(self, other)` with `s * self + t * other = g`,
+    /// returned as `(g, s, t)`
+    pub fn extended_gcd(&self, other: &Self) -> Result<(Self, Self, Self), PolyError> {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::unit(self, other), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::unit(self, other));
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1)?;
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1.clone(), &s0 - &(&q * &s1));
+            (t0, t1) = (t1.clone(), &t0 - &(&q * &t1));
+        }
+        let Some(lead) = r0.leading_coefficient() else {
+            return Ok((r0, s0, t0));
+        };
+        let lead_inv = lead
+            .inverse()
+            .ok_or(PolyError::NonInvertibleLeadingCoefficient)?;
+        Ok((
+            r0.scale(&lead_inv),
+            s0.scale(&lead_inv),
+            t0.scale(&lead_inv),
+        ))
+    }
+
+    /// Constant 1 of the ring of either operand, zero polynomial if both are zero
+    fn unit(a: &Self, b: &Self) -> Self {
+        match a.leading_coefficient().or(b.leading_coefficient()) {
+            Some(c) => Self::constant(c.ring().one()),
+            None => Self::zero(),
+        }
+    }
+
+    /// `self^exp mod modulus` by square-and-multiply
+    pub fn pow_mod(&self, exp: u64, modulus: &Self) -> Result<Self, PolyError> {
+        let lead = modulus
+            .leading_coefficient()
+            .ok_or(PolyError::DivisionByZero)?;
+        let mut result = Self::constant(lead.ring().one()).div_rem(modulus)?.1;
+        let base = self.div_rem(modulus)?.1;
+        for i in (0..u64::BITS - exp.leading_zeros()).rev() {
+            result = (&result * &result).div_rem(modulus)?.1;
+            if (exp >> i) & 1 == 1 {
+                result = (&result * &base).div_rem(modulus)?.1;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Latex form, e.g. `3x^{2} + x + 1`
+    pub fn to_latex(&self) -> String {
+        self.format("x", |i| format!("^{{{i}}}"))
+    }
+
+    fn format(&self, var: &str, power: impl Fn(usize) -> String) -> String {
+        let mut terms = vec![];
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let is_one = *c == c.ring().one();
+            let term = match (i, is_one) {
+                (0, _) => c.to_string(),
+                (1, true) => var.to_string(),
+                (1, false) => format!("{c}{var}"),
+                (_, true) => format!("{var}{}", power(i)),
+                (_, false) => format!("{c}{var}{}", power(i)),
+            };
+            terms.push(term);
+        }
+        if terms.is_empty() {
+            return "0".to_string();
+        }
+        terms.join(" + ")
+    }
+}
+
+/// Plain text form, e.g. `3x^2 + x + 1`
+impl<E: RingElement> std::fmt::Display for Polynomial<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format("x", |i| format!("^{i}")))
+    }
+}
+
+impl<E: RingElement> Add for &Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coefficients = long.coefficients.clone();
+        for (c, s) in coefficients.iter_mut().zip(&short.coefficients) {
+            *c = *c + *s;
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<E: RingElement> Neg for &Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn neg(self) -> Self::Output {
+        Polynomial::new(self.coefficients.iter().map(|c| -*c).collect())
+    }
+}
+
+impl<E: RingElement> Sub for &Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+/// NTT for large factors if the ring has suitable roots of unity, schoolbook otherwise
+impl<E: RingElement> Mul for &Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (Some(lhs_lead), Some(_)) = (self.leading_coefficient(), rhs.leading_coefficient())
+        else {
+            return Polynomial::zero();
+        };
+        if self.coefficients.len().min(rhs.coefficients.len()) >= NTT_THRESHOLD
+            && let Some(product) = ntt::multiply(self, rhs)
+        {
+            return product;
+        }
+        let zero = lhs_lead.ring().zero();
+        let mut coefficients = vec![zero; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j] + *a * *b;
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+macro_rules! impl_owned_op {
+    ($($op:ident $method:ident),*) => {
+        $(
+            impl<E: RingElement> $op for Polynomial<E> {
+                type Output = Polynomial<E>;
+                fn $method(self, rhs: Self) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_owned_op!(Add add, Sub sub, Mul mul);
+
+impl<E: RingElement> Neg for Polynomial<E> {
+    type Output = Polynomial<E>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
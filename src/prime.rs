@@ -0,0 +1,190 @@
+use crate::{
+    bigint::{BigRing, Uint},
+    montgomery::MontgomeryRing,
+    params::{ParamsError, SeededRng},
+    ring::{Exponent, Ring, RingElement},
+};
+
+/// Bases making Miller-Rabin deterministic for all `n < 2^64`
+const DETERMINISTIC_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Attempts made to find a prime before giving up
+const MAX_ATTEMPTS: usize = 100_000;
+
+/// One Miller-Rabin round for `n - 1 = d * 2^s`, `true` if `base` is not a witness of compositeness
+fn miller_rabin_round<E: RingElement>(base: E, d: impl Exponent, s: usize) -> bool {
+    let one = base.ring().one();
+    let minus_one = -one;
+    let mut x = base.pow(d);
+    if x == one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = x * x;
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+/// Deterministic Miller-Rabin test
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in DETERMINISTIC_BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros() as usize;
+    let d = (n - 1) >> s;
+    // Montgomery form needs n < 2^63, larger values fall back to a single limb big ring
+    match MontgomeryRing::new(n) {
+        Ok(ring) => DETERMINISTIC_BASES
+            .iter()
+            .all(|base| miller_rabin_round(ring.create_element(*base), d, s)),
+        Err(_) => {
+            let ring = BigRing::<1>::new(Uint::from(n)).expect("n > 37");
+            DETERMINISTIC_BASES
+                .iter()
+                .all(|base| miller_rabin_round(ring.create_element(Uint::from(*base)), d, s))
+        }
+    }
+}
+
+/// Probabilistic Miller-Rabin test with `rounds` random bases, a composite passes with
+/// probability at most `4^-rounds`
+pub fn is_probable_prime<const LIMBS: usize>(
+    n: &Uint<LIMBS>,
+    rounds: usize,
+    rng: &mut SeededRng,
+) -> bool {
+    if let Some(n) = n.to_u64() {
+        return is_prime(n);
+    }
+    for p in DETERMINISTIC_BASES {
+        if n.div_rem_u64(p).1 == 0 {
+            return false;
+        }
+    }
+    let ring = BigRing::new(*n).expect("n >= 2^64");
+    let n_minus_one = n.overflowing_sub(&Uint::from(1)).0;
+    let mut d = n_minus_one;
+    let mut s = 0;
+    while !d.bit(0) {
+        d = d.shr1();
+        s += 1;
+    }
+    // Bases in [2, n - 2]
+    let range = n.overflowing_sub(&Uint::from(3)).0;
+    (0..rounds).all(|_| {
+        let base = random_uint::<LIMBS>(rng).div_rem(&range).1;
+        let base = base.overflowing_add(&Uint::from(2)).0;
+        miller_rabin_round(ring.create_element(base), d, s)
+    })
+}
+
+fn random_uint<const LIMBS: usize>(rng: &mut SeededRng) -> Uint<LIMBS> {
+    Uint::from_limbs(std::array::from_fn(|_| rng.next_u64()))
+}
+
+/// Random odd value with exactly `bits` significant bits
+fn random_odd(rng: &mut SeededRng, bits: u32) -> u64 {
+    let value = rng.next_u64() >> (64 - bits);
+    value | (1 << (bits - 1)) | 1
+}
+
+fn check_bits(bits: u32, min: u32, max: u32) -> Result<(), ParamsError> {
+    if !(min..=max).contains(&bits) {
+        return Err(ParamsError::Unsatisfiable("prime bit length"));
+    }
+    Ok(())
+}
+
+/// Random prime with exactly `bits` significant bits, `2 <= bits <= 64`
+pub fn random_prime(rng: &mut SeededRng, bits: u32) -> Result<u64, ParamsError> {
+    check_bits(bits, 2, 64)?;
+    if bits == 2 {
+        return Ok(2 + rng.gen_range(0..=1)?);
+    }
+    for _ in 0..MAX_ATTEMPTS {
+        let candidate = random_odd(rng, bits);
+        if is_prime(candidate) {
+            return Ok(candidate);
+        }
+    }
+    Err(ParamsError::Unsatisfiable("prime"))
+}
+
+/// Random safe prime `p = 2q + 1` with prime `q` and exactly `bits` significant bits,
+/// `3 <= bits <= 64`
+pub fn random_safe_prime(rng: &mut SeededRng, bits: u32) -> Result<u64, ParamsError> {
+    check_bits(bits, 3, 64)?;
+    if bits == 3 {
+        // 5 and 7 are the only safe primes of 3 bits
+        return Ok(5 + 2 * rng.gen_range(0..=1)?);
+    }
+    for _ in 0..MAX_ATTEMPTS {
+        let q = random_odd(rng, bits - 1);
+        if is_prime(q) && is_prime(2 * q + 1) {
+            return Ok(2 * q + 1);
+        }
+    }
+    Err(ParamsError::Unsatisfiable("safe prime"))
+}
+
+/// Random prime `p = residue (mod module)` with exactly `bits` significant bits,
+/// e.g. `residue = 3, module = 4` for primes with easy square roots
+pub fn random_prime_with_residue(
+    rng: &mut SeededRng,
+    bits: u32,
+    residue: u64,
+    module: u64,
+) -> Result<u64, ParamsError> {
+    check_bits(bits, 2, 64)?;
+    if module == 0 {
+        return Err(ParamsError::Unsatisfiable("positive residue module"));
+    }
+    let low = 1u64 << (bits - 1);
+    let high = u64::MAX >> (64 - bits);
+    for _ in 0..MAX_ATTEMPTS {
+        let candidate = rng.gen_range(low..=high)?;
+        let Some(candidate) = (candidate - candidate % module).checked_add(residue % module) else {
+            continue;
+        };
+        if candidate >= low && candidate <= high && is_prime(candidate) {
+            return Ok(candidate);
+        }
+    }
+    Err(ParamsError::Unsatisfiable("prime with residue"))
+}
+
+/// Random probable prime with exactly `bits` significant bits
+pub fn random_big_prime<const LIMBS: usize>(
+    rng: &mut SeededRng,
+    bits: usize,
+    rounds: usize,
+) -> Result<Uint<LIMBS>, ParamsError> {
+    if !(2..=Uint::<LIMBS>::BITS).contains(&bits) {
+        return Err(ParamsError::Unsatisfiable("prime bit length"));
+    }
+    for _ in 0..MAX_ATTEMPTS {
+        let mut limbs = *random_uint::<LIMBS>(rng).limbs();
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            for bit in 0..64 {
+                if i * 64 + bit >= bits {
+                    *limb &= !(1 << bit);
+                }
+            }
+        }
+        limbs[(bits - 1) / 64] |= 1 << ((bits - 1) % 64);
+        limbs[0] |= 1;
+        let candidate = Uint::from_limbs(limbs);
+        if is_probable_prime(&candidate, rounds, rng) {
+            return Ok(candidate);
+        }
+    }
+    Err(ParamsError::Unsatisfiable("prime"))
+}
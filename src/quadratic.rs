@@ -0,0 +1,215 @@
+//! Quadratic residues and modular square roots
+
+use crate::{
+    bigint::{BigRing, Uint},
+    crt::solve_crt,
+    factor::factorize,
+    field::{Field, PrimeField},
+    integer::inverse_mod,
+    ring::{Ring, RingElement},
+};
+
+/// Legendre symbol `(a/p)` for an odd prime `p` by Euler's criterion `a^((p-1)/2)`
+pub fn legendre(a: u64, p: u64) -> i8 {
+    if p < 3 || p.is_multiple_of(2) {
+        panic!("Legendre symbol needs an odd prime, got {p}");
+    }
+    let ring = BigRing::<1>::new(Uint::from(p)).expect("p >= 3");
+    let power = ring.create_element(Uint::from(a)).pow((p - 1) / 2);
+    symbol(power)
+}
+
+/// Jacobi symbol `(a/n)` for odd `n` by quadratic reciprocity
+pub fn jacobi(a: u64, n: u64) -> i8 {
+    if n.is_multiple_of(2) {
+        panic!("Jacobi symbol needs an odd module, got {n}");
+    }
+    let (mut a, mut n) = (a % n, n);
+    let mut result = 1;
+    while a != 0 {
+        while a.is_multiple_of(2) {
+            a /= 2;
+            // (2/n) = -1 iff n = 3, 5 (mod 8)
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 { result } else { 0 }
+}
+
+/// Maps `x^((q-1)/2)` to the Legendre symbol
+fn symbol<E: RingElement>(power: E) -> i8 {
+    let one = power.ring().one();
+    if power == one {
+        1
+    } else if power == -one {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Legendre symbol of a field element by Euler's criterion, `q` is the field order.
+///
+/// Takes any `RingElement`, so `SmallRingElement` with a prime module works too.
+pub fn field_legendre<E: RingElement>(a: &E, q: u64) -> i8 {
+    symbol(a.pow((q - 1) / 2))
+}
+
+/// Candidates `2, 3, ...` of the prime subfield
+fn small_elements<E: Field>(a: &E) -> impl Iterator<Item = E> {
+    let one = a.ring().one();
+    std::iter::successors(Some(one + one), move |x| Some(*x + one))
+}
+
+/// Tonelli-Shanks square root in a field of odd order `q`.
+///
+/// The non-residue is searched among `2, 3, ...` of the prime subfield, which always succeeds
+/// in prime fields.
+pub fn tonelli_shanks<E: Field>(a: &E, q: u64) -> Option<E> {
+    let zero = a.ring().zero();
+    if *a == zero {
+        return Some(zero);
+    }
+    if field_legendre(a, q) != 1 {
+        return None;
+    }
+    let z = small_elements(a)
+        .take(a.characteristic() as usize)
+        .find(|z| field_legendre(z, q) == -1)?;
+    Some(tonelli_shanks_core(a, q, z))
+}
+
+/// Same as `tonelli_shanks` with a known quadratic non-residue `z`
+pub fn tonelli_shanks_with<E: Field>(a: &E, q: u64, z: &E) -> Option<E> {
+    let zero = a.ring().zero();
+    if *a == zero {
+        return Some(zero);
+    }
+    if field_legendre(a, q) != 1 {
+        return None;
+    }
+    Some(tonelli_shanks_core(a, q, *z))
+}
+
+/// Square root of a non-zero square `a` given a non-residue `z`
+fn tonelli_shanks_core<E: Field>(a: &E, q: u64, z: E) -> E {
+    // q - 1 = d * 2^s
+    let s = (q - 1).trailing_zeros();
+    let d = (q - 1) >> s;
+    let one = a.ring().one();
+    let mut m = s;
+    let mut c = z.pow(d);
+    let mut t = a.pow(d);
+    let mut r = a.pow(d.div_ceil(2));
+    while t != one {
+        // Least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t_pow = t;
+        while t_pow != one {
+            t_pow = t_pow * t_pow;
+            i += 1;
+        }
+        let b = c.pow(1u64 << (m - i - 1));
+        m = i;
+        c = b * b;
+        t = t * c;
+        r = r * b;
+    }
+    r
+}
+
+/// Cipolla square root in a field of odd order `q`: finds `t` with non-residue `t^2 - a` and
+/// computes `(t + w)^((q+1)/2)` in `F[w] / (w^2 - (t^2 - a))`
+pub fn cipolla<E: Field>(a: &E, q: u64) -> Option<E> {
+    let zero = a.ring().zero();
+    if *a == zero {
+        return Some(zero);
+    }
+    if field_legendre(a, q) != 1 {
+        return None;
+    }
+    let (t, w2) = small_elements(a)
+        .take(a.characteristic() as usize)
+        .map(|t| (t, t * t - *a))
+        .find(|(_, w2)| field_legendre(w2, q) == -1)?;
+    let mul = |(x1, y1): (E, E), (x2, y2): (E, E)| (x1 * x2 + y1 * y2 * w2, x1 * y2 + x2 * y1);
+    let exp = q.div_ceil(2);
+    let mut result = (a.ring().one(), zero);
+    for i in (0..u64::BITS - exp.leading_zeros()).rev() {
+        result = mul(result, result);
+        if (exp >> i) & 1 == 1 {
+            result = mul(result, (t, a.ring().one()));
+        }
+    }
+    Some(result.0)
+}
+
+/// Square root of `a` modulo arbitrary `n`: roots modulo prime powers of `n` are lifted with
+/// Hensel's lemma and combined with the CRT. Odd prime factors of `n` must fit into `u32`.
+pub fn sqrt_mod(a: u64, n: u64) -> Option<u64> {
+    if n == 0 {
+        panic!("Square root modulo zero is undefined");
+    }
+    let mut congruences = vec![];
+    for (p, e) in factorize(n).factors() {
+        let root = sqrt_mod_prime_power(a % p.pow(*e), *p, *e)?;
+        congruences.push([root as i64, p.pow(*e) as i64]);
+    }
+    let (root, _, _) = solve_crt(&congruences).ok()?;
+    Some(root as u64)
+}
+
+fn sqrt_mod_prime_power(a: u64, p: u64, e: u32) -> Option<u64> {
+    let module = p.pow(e);
+    if a == 0 {
+        return Some(0);
+    }
+    // a = p^v * b, x = p^(v/2) * y with y^2 = b (mod p^(e - v))
+    let mut v = 0;
+    let mut b = a;
+    while b.is_multiple_of(p) {
+        b /= p;
+        v += 1;
+    }
+    if v > 0 {
+        if v % 2 == 1 {
+            return None;
+        }
+        let y = sqrt_mod_prime_power(b % p.pow(e - v), p, e - v)?;
+        return Some(p.pow(v / 2) * y % module);
+    }
+    if p == 2 {
+        return sqrt_mod_power_of_two(a, e);
+    }
+    let field = PrimeField::new(u32::try_from(p).ok()?).ok()?;
+    let mut x = tonelli_shanks(&field.create_element(a % p), p)?.value() as u128;
+    // x^2 = a (mod p^k)  =>  x' = x - (x^2 - a) / (2x) (mod p^(k+1))
+    let mut p_k = p as u128;
+    for _ in 1..e {
+        p_k *= p as u128;
+        let fx = (x * x % p_k + p_k - a as u128 % p_k) % p_k;
+        let derivative_inv = inverse_mod((2 * x % p_k) as u64, p_k as u64)? as u128;
+        x = (x + p_k - fx * derivative_inv % p_k) % p_k;
+    }
+    Some(x as u64)
+}
+
+/// Odd `a`: roots modulo 8 are lifted by `x -> x` or `x -> x + 2^(k-1)`
+fn sqrt_mod_power_of_two(a: u64, e: u32) -> Option<u64> {
+    let small = 1u64 << e.min(3);
+    let mut x = (1..small).find(|x| x * x % small == a % small)?;
+    for k in 3..e {
+        let module = 1u128 << (k + 1);
+        if (x as u128 * x as u128) % module != a as u128 % module {
+            x += 1 << (k - 1);
+        }
+    }
+    Some(x)
+}
@@ -1,15 +1,82 @@
 use std::{
     cmp,
-    collections::HashMap,
     hash::Hash,
-    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    ops::{Add, Mul, Neg, Rem, Sub},
+};
+
+use crate::{
+    ntt,
+    quadratic::{jacobi, sqrt_mod},
+    units::{carmichael, element_order},
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingError {
+    /// Modules 0 and 1 don't define a useful ring
+    InvalidModule(u32),
+    /// Value is not a canonical representative, i.e. `value >= module`
+    ValueOutOfRange { value: u64, module: u32 },
+    /// Module is not supported by the ring backend, e.g. even module in Montgomery form
+    UnsupportedModule(u64),
+    /// Field was requested over a composite module
+    NotPrime(u64),
+    /// Operands belong to rings with different modules
+    RingMismatch { lhs: u32, rhs: u32 },
+    /// Remainder of division by zero element
+    DivisionByZero,
+}
+
+impl std::fmt::Display for RingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RingError::InvalidModule(module) => {
+                write!(f, "Invalid ring module {module}, expected at least 2")
+            }
+            RingError::ValueOutOfRange { value, module } => {
+                write!(f, "Value {value} is out of range for ring (mod {module})")
+            }
+            RingError::UnsupportedModule(module) => {
+                write!(f, "Module {module} is not supported by the ring")
+            }
+            RingError::NotPrime(module) => write!(f, "Module {module} is not prime"),
+            RingError::RingMismatch { lhs, rhs } => {
+                write!(
+                    f,
+                    "Ring mismatch, lhs ring (mod {lhs}), rhs ring (mod {rhs})"
+                )
+            }
+            RingError::DivisionByZero => f.write_str("Division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for RingError {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SmallRing {
     module: u32,
 }
 
+impl SmallRing {
+    pub fn new(module: u32) -> Result<Self, RingError> {
+        if module < 2 {
+            return Err(RingError::InvalidModule(module));
+        }
+        Ok(Self { module })
+    }
+
+    /// Same as `create_element`, but rejects values that are not reduced instead of reducing them
+    pub fn try_create_element(&self, value: u64) -> Result<SmallRingElement, RingError> {
+        if value >= self.module as u64 {
+            return Err(RingError::ValueOutOfRange {
+                value,
+                module: self.module,
+            });
+        }
+        Ok(SmallRingElement { ring: *self, value })
+    }
+}
+
 impl std::fmt::Display for SmallRing {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("Ring (mod ")?;
@@ -23,15 +90,28 @@ impl Ring for SmallRing {
     type Module = u32;
     type Value = u64;
 
-    fn create_element<'a>(&self, value: Self::Value) -> Self::Element {
+    /// Creates an element reducing `value` by the ring module
+    fn create_element(&self, value: Self::Value) -> Self::Element {
         SmallRingElement {
-            ring: self.clone(),
-            value,
+            ring: *self,
+            value: value % self.module as u64,
         }
     }
     fn module(&self) -> &Self::Module {
         &self.module
     }
+
+    fn zero(&self) -> Self::Element {
+        self.create_element(0)
+    }
+
+    fn one(&self) -> Self::Element {
+        self.create_element(1)
+    }
+
+    fn cardinality(&self) -> Option<u64> {
+        Some(self.module as u64)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -40,66 +120,94 @@ pub struct SmallRingElement {
     value: u64,
 }
 
-impl Add for SmallRingElement {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
+impl SmallRingElement {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn module(&self) -> u32 {
+        self.ring.module
+    }
+
+    /// Jacobi symbol `(value/module)`, panics for even modules
+    pub fn jacobi(&self) -> i8 {
+        jacobi(self.value, self.ring.module as u64)
+    }
+
+    /// Some square root modulo a possibly composite module, `None` for non-squares
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = sqrt_mod(self.value, self.ring.module as u64)?;
+        Some(self.ring.create_element(root))
+    }
+
+    fn check_ring(&self, rhs: &Self) -> Result<(), RingError> {
         if self.ring != rhs.ring {
-            panic!(
-                "Ring operation failed, lhs ring: {}, rhs ring: {}",
-                self.ring, rhs.ring
-            );
+            return Err(RingError::RingMismatch {
+                lhs: self.ring.module,
+                rhs: rhs.ring.module,
+            });
         }
-        let value = (self.value + rhs.value) % (*self.ring.module() as u64);
+        Ok(())
+    }
 
-        Self {
-            ring: self.ring.clone(),
-            value,
+    pub fn checked_add(self, rhs: Self) -> Result<Self, RingError> {
+        self.check_ring(&rhs)?;
+        Ok(self.ring.create_element(self.value + rhs.value))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, RingError> {
+        self.check_ring(&rhs)?;
+        Ok(self
+            .ring
+            .create_element(self.value + self.ring.module as u64 - rhs.value))
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, RingError> {
+        self.check_ring(&rhs)?;
+        // Both values are below 2^32, so the product fits into u64
+        Ok(self.ring.create_element(self.value * rhs.value))
+    }
+
+    /// Remainder of canonical representatives, `value % rhs.value`
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, RingError> {
+        self.check_ring(&rhs)?;
+        if rhs.value == 0 {
+            return Err(RingError::DivisionByZero);
         }
+        Ok(self.ring.create_element(self.value % rhs.value))
+    }
+}
+
+/// Operators panic where the `checked_*` counterparts return an error
+impl Add for SmallRingElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|e| panic!("Ring operation failed: {e}"))
     }
 }
 
 impl Sub for SmallRingElement {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        if self.ring != rhs.ring {
-            panic!(
-                "Ring operation failed, lhs ring: {}, rhs ring: {}",
-                self.ring, rhs.ring
-            );
-        }
-        let value =
-            (self.value + *self.ring.module() as u64 + rhs.value) % (*self.ring.module() as u64);
-
-        Self {
-            ring: self.ring.clone(),
-            value,
-        }
+        self.checked_sub(rhs)
+            .unwrap_or_else(|e| panic!("Ring operation failed: {e}"))
     }
 }
 
 impl Mul for SmallRingElement {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        if self.ring != rhs.ring {
-            panic!(
-                "Ring operation failed, lhs ring: {}, rhs ring: {}",
-                self.ring, rhs.ring
-            );
-        }
-        let value = (self.value * rhs.value) % (*self.ring.module() as u64);
-
-        Self {
-            ring: self.ring.clone(),
-            value,
-        }
+        self.checked_mul(rhs)
+            .unwrap_or_else(|e| panic!("Ring operation failed: {e}"))
     }
 }
 
 impl Rem for SmallRingElement {
     type Output = SmallRingElement;
     fn rem(self, rhs: Self) -> Self::Output {
-        let value = self.value % rhs.value;
-        self.ring.create_element(value)
+        self.checked_rem(rhs)
+            .unwrap_or_else(|e| panic!("Ring operation failed: {e}"))
     }
 }
 
@@ -107,14 +215,15 @@ impl Neg for SmallRingElement {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
+        // Reduction maps `module - 0` back to 0
         self.ring
-            .create_element(*self.ring.module() as u64 - self.value)
+            .create_element(self.ring.module as u64 - self.value)
     }
 }
 
 impl PartialOrd for SmallRingElement {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.value.partial_cmp(&other.value)
+        Some(self.cmp(other))
     }
 }
 
@@ -130,7 +239,9 @@ impl std::fmt::Display for SmallRingElement {
     }
 }
 impl RingElement for SmallRingElement {
-    fn ring(&self) -> &impl Ring {
+    type Ring = SmallRing;
+
+    fn ring(&self) -> &Self::Ring {
         &self.ring
     }
     fn inverse(&self) -> Option<Self> {
@@ -144,6 +255,11 @@ impl RingElement for SmallRingElement {
         let inv_elem = self.ring.create_element(modular_value as u64);
         Some(inv_elem)
     }
+
+    /// Exists only for prime modules
+    fn root_of_unity(&self, log_n: u32) -> Option<Self> {
+        ntt::root_of_unity(&self.ring, log_n)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +270,30 @@ pub struct ExtendedEuclideanView {
     up: Vec<[i64; 4]>,
 }
 
+impl ExtendedEuclideanView {
+    /// Rows `[x, y, m, r]` of the division steps
+    pub fn down(&self) -> &[[i64; 4]] {
+        &self.down
+    }
+
+    /// Rows `[x, n, y, m]` of the back substitution steps
+    pub fn up(&self) -> &[[i64; 4]] {
+        &self.up
+    }
+}
+
+impl std::fmt::Display for ExtendedEuclideanView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for [x, y, m, r] in &self.down {
+            writeln!(f, "{x} = {y}*{m} + {r}")?;
+        }
+        for [x, n, y, m] in &self.up {
+            writeln!(f, "{x}*{n} + {y}*{m} = 1")?;
+        }
+        Ok(())
+    }
+}
+
 pub fn extended_euclidean(x: i64, y: i64) -> Result<(i64, i64, ExtendedEuclideanView), i64> {
     let x = x.abs();
     let y = y.abs();
@@ -213,6 +353,100 @@ pub trait Ring: std::fmt::Debug + Clone + Send + Sync + 'static {
 
     fn create_element(&self, value: Self::Value) -> Self::Element;
     fn module(&self) -> &Self::Module;
+    fn zero(&self) -> Self::Element;
+    fn one(&self) -> Self::Element;
+    /// Number of elements, `None` if it doesn't fit into `u64`
+    fn cardinality(&self) -> Option<u64>;
+}
+
+/// Exponent for square-and-multiply, read bit by bit
+pub trait Exponent {
+    /// Number of significant bits
+    fn bits(&self) -> usize;
+    fn bit(&self, i: usize) -> bool;
+}
+
+macro_rules! impl_exponent {
+    ($($t:ty),*) => {
+        $(
+            impl Exponent for $t {
+                fn bits(&self) -> usize {
+                    (<$t>::BITS - self.leading_zeros()) as usize
+                }
+                fn bit(&self, i: usize) -> bool {
+                    (self >> i) & 1 == 1
+                }
+            }
+        )*
+    };
+}
+
+impl_exponent!(u8, u16, u32, u64, u128, usize);
+
+/// Step of left-to-right square-and-multiply for one exponent bit
+#[derive(Debug, Clone)]
+pub struct PowStep<E> {
+    pub bit: bool,
+    /// Accumulator before the step
+    pub acc: E,
+    /// acc^2
+    pub squared: E,
+    /// acc^2 * base if `bit` is set, else acc^2
+    pub result: E,
+}
+
+#[derive(Debug, Clone)]
+pub struct PowView<E> {
+    base: E,
+    /// Exponent bits, most significant first
+    bits: Vec<bool>,
+    steps: Vec<PowStep<E>>,
+}
+
+impl<E> PowView<E> {
+    pub fn base(&self) -> &E {
+        &self.base
+    }
+
+    pub fn bits(&self) -> &[bool] {
+        &self.bits
+    }
+
+    /// Steps for all bits but the leading one, which just sets the accumulator to `base`
+    pub fn steps(&self) -> &[PowStep<E>] {
+        &self.steps
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for PowView<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("exponent bits: ")?;
+        for bit in &self.bits {
+            f.write_str(if *bit { "1" } else { "0" })?;
+        }
+        writeln!(f)?;
+        if !self.bits.is_empty() {
+            writeln!(f, "1: acc = {}", self.base)?;
+        }
+        for step in &self.steps {
+            let PowStep {
+                bit,
+                acc,
+                squared,
+                result,
+            } = step;
+            if *bit {
+                writeln!(
+                    f,
+                    "1: {acc}^2 = {squared}, {squared}*{} = {result}",
+                    self.base
+                )?;
+            } else {
+                writeln!(f, "0: {acc}^2 = {squared}")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 pub trait RingElement:
@@ -220,7 +454,7 @@ pub trait RingElement:
     + Sub<Output = Self>
     + Mul<Output = Self>
     + Rem<Output = Self>
-    + Neg
+    + Neg<Output = Self>
     + PartialOrd
     + Ord
     + Hash
@@ -232,6 +466,79 @@ pub trait RingElement:
     + Sync
     + 'static
 {
-    fn ring(&self) -> &impl Ring;
+    type Ring: Ring<Element = Self>;
+
+    fn ring(&self) -> &Self::Ring;
     fn inverse(&self) -> Option<Self>;
+
+    fn is_zero(&self) -> bool {
+        *self == self.ring().zero()
+    }
+
+    /// `self + ... + self` (`k` times) by double-and-add
+    fn mul_u64(&self, k: u64) -> Self {
+        let mut result = self.ring().zero();
+        for i in (0..u64::BITS - k.leading_zeros()).rev() {
+            result = result + result;
+            if (k >> i) & 1 == 1 {
+                result = result + *self;
+            }
+        }
+        result
+    }
+
+    /// Smallest `k > 0` with `self^k = 1`, `None` for non-units.
+    ///
+    /// The order divides the exponent of the unit group, which is λ(n) for `Z/nZ`. Rings that
+    /// are not `Z/nZ` should override this.
+    fn multiplicative_order(&self) -> Option<u64> {
+        let n = self.ring().cardinality()?;
+        self.inverse()?;
+        Some(element_order(self, carmichael(n)))
+    }
+
+    /// Primitive `2^log_n`-th root of unity used by the NTT, `None` if the ring doesn't provide
+    /// one
+    fn root_of_unity(&self, _log_n: u32) -> Option<Self> {
+        None
+    }
+
+    /// Left-to-right square-and-multiply
+    fn pow(&self, exp: impl Exponent) -> Self {
+        let mut result = self.ring().one();
+        for i in (0..exp.bits()).rev() {
+            result = result * result;
+            if exp.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    /// Same as `pow`, but records every squaring and multiplication
+    fn pow_with_view(&self, exp: impl Exponent) -> (Self, PowView<Self>) {
+        let bits: Vec<bool> = (0..exp.bits()).rev().map(|i| exp.bit(i)).collect();
+        let mut steps = vec![];
+        let mut result = self.ring().one();
+        if !bits.is_empty() {
+            result = *self;
+        }
+        for bit in bits.iter().skip(1) {
+            let acc = result;
+            let squared = acc * acc;
+            result = if *bit { squared * *self } else { squared };
+            steps.push(PowStep {
+                bit: *bit,
+                acc,
+                squared,
+                result,
+            });
+        }
+        let view = PowView {
+            base: *self,
+            bits,
+            steps,
+        };
+        (result, view)
+    }
 }
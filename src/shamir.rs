@@ -0,0 +1,171 @@
+//! Shamir's threshold secret sharing: the secret is `f(0)` of a random polynomial of degree
+//! `threshold - 1`, shares are points `(i, f(i))`
+
+use crate::{
+    interpolation::{InterpolationError, LagrangeView, lagrange},
+    params::{ParamsError, SeededRng},
+    poly::Polynomial,
+    ring::{Ring, RingElement},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShamirError {
+    /// Threshold must be in `1..=count` and `count` below the ring cardinality
+    InvalidThreshold {
+        threshold: usize,
+        count: usize,
+    },
+    /// Shares can only be sampled in finite rings
+    InfiniteRing,
+    NotEnoughShares {
+        threshold: usize,
+        given: usize,
+    },
+    /// No polynomial of degree below `threshold` passes through all shares
+    Inconsistent,
+    Interpolation(InterpolationError),
+    Params(ParamsError),
+}
+
+impl std::fmt::Display for ShamirError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShamirError::InvalidThreshold { threshold, count } => {
+                write!(f, "Invalid threshold {threshold} for {count} shares")
+            }
+            ShamirError::InfiniteRing => f.write_str("Shares need a finite ring"),
+            ShamirError::NotEnoughShares { threshold, given } => {
+                write!(f, "Need {threshold} shares, got {given}")
+            }
+            ShamirError::Inconsistent => f.write_str("Shares are mutually inconsistent"),
+            ShamirError::Interpolation(e) => e.fmt(f),
+            ShamirError::Params(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ShamirError {}
+
+impl From<InterpolationError> for ShamirError {
+    fn from(e: InterpolationError) -> Self {
+        ShamirError::Interpolation(e)
+    }
+}
+
+impl From<ParamsError> for ShamirError {
+    fn from(e: ParamsError) -> Self {
+        ShamirError::Params(e)
+    }
+}
+
+/// Point `(x, f(x))` of the sharing polynomial
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Share<E> {
+    pub x: E,
+    pub y: E,
+}
+
+impl<E: RingElement> std::fmt::Display for Share<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Splits `secret` into shares at `x = 1..=count`, any `threshold` of them recover it.
+///
+/// Returns the shares and the sharing polynomial.
+pub fn split<E: RingElement>(
+    secret: &E,
+    threshold: usize,
+    count: usize,
+    rng: &mut SeededRng,
+) -> Result<(Vec<Share<E>>, Polynomial<E>), ShamirError> {
+    let cardinality = secret
+        .ring()
+        .cardinality()
+        .ok_or(ShamirError::InfiniteRing)?;
+    if threshold == 0 || threshold > count || count as u64 >= cardinality {
+        return Err(ShamirError::InvalidThreshold { threshold, count });
+    }
+    let one = secret.ring().one();
+    let mut coefficients = vec![*secret];
+    for _ in 1..threshold {
+        coefficients.push(one.mul_u64(rng.gen_range(0..=cardinality - 1)?));
+    }
+    let polynomial = Polynomial::new(coefficients);
+    let shares = (1..=count as u64)
+        .map(|i| {
+            let x = one.mul_u64(i);
+            Share {
+                x,
+                y: polynomial.evaluate(&x),
+            }
+        })
+        .collect();
+    Ok((shares, polynomial))
+}
+
+/// Lagrange coefficients `λ_i = L_i(0)` used to recover the secret
+#[derive(Debug, Clone)]
+pub struct ReconstructionView<E> {
+    lagrange: LagrangeView<E>,
+}
+
+impl<E> ReconstructionView<E> {
+    pub fn lagrange(&self) -> &LagrangeView<E> {
+        &self.lagrange
+    }
+}
+
+impl<E: RingElement> std::fmt::Display for ReconstructionView<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut terms = vec![];
+        let mut secret = None;
+        for (i, basis) in self.lagrange.bases().iter().enumerate() {
+            let zero = basis.node.ring().zero();
+            let numerator = basis.numerator.evaluate(&zero);
+            let coefficient = basis.basis.evaluate(&zero);
+            writeln!(
+                f,
+                "λ_{i} = L_{i}(0) = {numerator} * {}^(-1) = {numerator} * {} = {coefficient}",
+                basis.denominator, basis.denominator_inv
+            )?;
+            terms.push(format!("{} * λ_{i}", basis.value));
+            let term = basis.value * coefficient;
+            secret = Some(secret.map_or(term, |s| s + term));
+        }
+        match secret {
+            Some(secret) => writeln!(f, "s = {} = {secret}", terms.join(" + ")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Recovers the secret by Lagrange interpolation at zero through the first `threshold` shares.
+///
+/// Extra shares are checked against the interpolated polynomial. A mismatch doesn't tell which
+/// share is wrong, exactly `threshold` shares are never rejected.
+pub fn reconstruct<E: RingElement>(
+    shares: &[Share<E>],
+    threshold: usize,
+) -> Result<(E, ReconstructionView<E>), ShamirError> {
+    if threshold == 0 || shares.len() < threshold {
+        return Err(ShamirError::NotEnoughShares {
+            threshold,
+            given: shares.len(),
+        });
+    }
+    let points: Vec<[E; 2]> = shares[..threshold]
+        .iter()
+        .map(|share| [share.x, share.y])
+        .collect();
+    let (polynomial, lagrange) = lagrange(&points)?;
+    if shares[threshold..]
+        .iter()
+        .any(|share| polynomial.evaluate(&share.x) != share.y)
+    {
+        return Err(ShamirError::Inconsistent);
+    }
+    let secret = polynomial.evaluate(&shares[0].x.ring().zero());
+    Ok((secret, ReconstructionView { lagrange }))
+}
@@ -0,0 +1,218 @@
+//! Structure of the unit group `(Z/nZ)*`: orders, exponent and generators
+
+use crate::{
+    bigint::{BigRing, Uint},
+    factor::{Factorization, factorize},
+    integer::{gcd, lcm},
+    ring::{Ring, RingElement, SmallRing, SmallRingElement},
+};
+
+/// Euler's totient φ(n), the order of `(Z/nZ)*`
+pub fn euler_totient(n: u64) -> u64 {
+    if n == 0 {
+        panic!("Totient of zero is undefined");
+    }
+    factorize(n)
+        .factors()
+        .iter()
+        .map(|(p, e)| p.pow(e - 1) * (p - 1))
+        .product()
+}
+
+/// Carmichael function λ(n), the exponent of `(Z/nZ)*`: the smallest `m` with `a^m = 1`
+/// for all units `a`
+pub fn carmichael(n: u64) -> u64 {
+    if n == 0 {
+        panic!("Carmichael function of zero is undefined");
+    }
+    factorize(n)
+        .factors()
+        .iter()
+        .map(|(p, e)| match (p, e) {
+            // (Z/2^kZ)* = C2 x C(2^(k-2)) for k >= 3
+            (2, e) if *e >= 3 => 1 << (e - 2),
+            (p, e) => p.pow(e - 1) * (p - 1),
+        })
+        .fold(1, lcm)
+}
+
+/// Multiplicative order of `a` modulo `n`, `None` if `a` is not a unit
+pub fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    if n == 0 {
+        panic!("Order modulo zero is undefined");
+    }
+    if gcd(a % n, n) != 1 {
+        return None;
+    }
+    if n == 1 {
+        return Some(1);
+    }
+    let ring = BigRing::<1>::new(Uint::from(n)).expect("n >= 2");
+    Some(element_order(
+        &ring.create_element(Uint::from(a)),
+        carmichael(n),
+    ))
+}
+
+/// Order of a unit element given a multiple `exponent` of its order
+pub fn element_order<E: RingElement>(element: &E, exponent: u64) -> u64 {
+    let one = element.ring().one();
+    order_dividing(exponent, &factorize(exponent), |exp| {
+        element.pow(exp) == one
+    })
+}
+
+/// Smallest divisor `d` of `exponent` with `is_identity(d)`, provided `is_identity(exponent)`.
+/// Strips prime factors of `exponent` one by one while the power stays trivial.
+pub(crate) fn order_dividing(
+    exponent: u64,
+    factorization: &Factorization,
+    is_identity: impl Fn(u64) -> bool,
+) -> u64 {
+    let mut order = exponent;
+    for q in factorization.primes() {
+        while order.is_multiple_of(q) && is_identity(order / q) {
+            order /= q;
+        }
+    }
+    order
+}
+
+/// `(Z/nZ)*` is cyclic iff n is 1, 2, 4, p^k or 2p^k for an odd prime p
+pub fn has_primitive_root(n: u64) -> bool {
+    if n == 0 {
+        panic!("Unit group modulo zero is undefined");
+    }
+    if n <= 4 {
+        return true;
+    }
+    let odd = if n.is_multiple_of(4) {
+        return false;
+    } else if n.is_multiple_of(2) {
+        n / 2
+    } else {
+        n
+    };
+    factorize(odd).factors().len() == 1
+}
+
+/// Smallest generator of `(Z/nZ)*`, `None` if the group is not cyclic
+pub fn primitive_root(n: u64) -> Option<u64> {
+    if !has_primitive_root(n) {
+        return None;
+    }
+    if n <= 2 {
+        return Some(n - 1);
+    }
+    let totient = factorize(euler_totient(n));
+    (2..n).find(|g| check_generator_with(*g, n, totient.clone()).is_generator())
+}
+
+/// Explanation of whether `candidate` generates `(Z/nZ)*`
+#[derive(Debug, Clone)]
+pub struct GeneratorCheck {
+    pub candidate: u64,
+    pub module: u64,
+    pub totient: Factorization,
+    /// `[q, candidate^(φ/q)]` for every prime `q` dividing φ(n), empty for non-units
+    pub powers: Vec<[u64; 2]>,
+    pub is_unit: bool,
+}
+
+impl GeneratorCheck {
+    /// A unit generates the group iff `g^(φ/q) != 1` for every prime `q | φ(n)`
+    pub fn is_generator(&self) -> bool {
+        self.is_unit
+            && self
+                .powers
+                .iter()
+                .all(|[_, power]| *power != 1 % self.module)
+    }
+}
+
+impl std::fmt::Display for GeneratorCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (g, n) = (self.candidate, self.module);
+        if !self.is_unit {
+            return writeln!(f, "gcd({g}, {n}) != 1, so {g} is not a unit");
+        }
+        let phi = self.totient.value();
+        writeln!(f, "φ({n}) = {phi} = {}", self.totient)?;
+        for [q, power] in &self.powers {
+            write!(f, "{g}^({phi}/{q}) = {g}^{} = {power}", phi / q)?;
+            if *power != 1 % n {
+                write!(f, " != 1")?;
+            }
+            writeln!(f)?;
+        }
+        if self.is_generator() {
+            writeln!(f, "{g} is a generator")
+        } else {
+            writeln!(f, "{g} is not a generator")
+        }
+    }
+}
+
+pub fn check_generator(candidate: u64, n: u64) -> GeneratorCheck {
+    check_generator_with(candidate, n, factorize(euler_totient(n)))
+}
+
+/// `check_generator` with the factorization of φ(n) computed by the caller
+fn check_generator_with(candidate: u64, n: u64, totient: Factorization) -> GeneratorCheck {
+    let is_unit = gcd(candidate % n, n) == 1;
+    let mut powers = vec![];
+    if is_unit && n > 1 {
+        let ring = BigRing::<1>::new(Uint::from(n)).expect("n >= 2");
+        let g = ring.create_element(Uint::from(candidate));
+        let phi = totient.value();
+        for q in totient.primes() {
+            let power = g.pow(phi / q).value().to_u64().expect("Single limb");
+            powers.push([q, power]);
+        }
+    }
+    GeneratorCheck {
+        candidate,
+        module: n,
+        totient,
+        powers,
+        is_unit,
+    }
+}
+
+/// Structure of the unit group of a ring
+#[derive(Debug, Clone)]
+pub struct UnitGroup {
+    /// φ(n)
+    pub order: u64,
+    /// λ(n)
+    pub exponent: u64,
+    /// Smallest generator if the group is cyclic
+    pub generator: Option<SmallRingElement>,
+}
+
+impl UnitGroup {
+    pub fn is_cyclic(&self) -> bool {
+        self.order == self.exponent
+    }
+}
+
+impl std::fmt::Display for UnitGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "order {}, exponent {}", self.order, self.exponent)?;
+        match self.generator {
+            Some(g) => write!(f, ", cyclic with generator {g}"),
+            None => f.write_str(", not cyclic"),
+        }
+    }
+}
+
+impl SmallRing {
+    pub fn unit_group(&self) -> UnitGroup {
+        let n = *self.module() as u64;
+        UnitGroup {
+            order: euler_totient(n),
+            exponent: carmichael(n),
+            generator: primitive_root(n).map(|g| self.create_element(g)),
+        }
+    }
+}
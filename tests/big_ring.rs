@@ -0,0 +1,140 @@
+use zk_exam::{
+    bigint::{BigRing, ParseUintError, U256, Uint},
+    ring::{Ring, RingElement},
+};
+
+/// splitmix64, so the values don't depend on the crate's own generator
+fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Random values of every bit length up to 128
+fn samples() -> Vec<u128> {
+    let mut state = 1;
+    let mut samples = vec![0, 1, 2, u64::MAX as u128, 1 << 64, u128::MAX];
+    for bits in 1..=128 {
+        let value = ((next(&mut state) as u128) << 64) | next(&mut state) as u128;
+        samples.push(value >> (128 - bits));
+    }
+    samples
+}
+
+fn uint(value: u128) -> Uint<2> {
+    Uint::from_limbs([value as u64, (value >> 64) as u64])
+}
+
+#[test]
+fn arithmetic_matches_u128() {
+    let samples = samples();
+    for &a in &samples {
+        let x = uint(a);
+        assert_eq!(x.bits(), 128 - a.leading_zeros() as usize);
+        assert_eq!(x.to_u64(), u64::try_from(a).ok());
+        assert_eq!(x.shr1(), uint(a >> 1));
+        assert_eq!(x.overflowing_shl1(), (uint(a << 1), a >> 127 == 1));
+        for &b in &samples {
+            let y = uint(b);
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            let (sum, overflow) = a.overflowing_add(b);
+            assert_eq!(x.overflowing_add(&y), (uint(sum), overflow));
+            let (diff, borrow) = a.overflowing_sub(b);
+            assert_eq!(x.overflowing_sub(&y), (uint(diff), borrow));
+            if let Some(quotient) = a.checked_div(b) {
+                assert_eq!(x.div_rem(&y), (uint(quotient), uint(a % b)), "{a} / {b}");
+            }
+            if let Ok(divisor) = u64::try_from(b)
+                && divisor != 0
+            {
+                let (quotient, remainder) = x.div_rem_u64(divisor);
+                assert_eq!((quotient, remainder as u128), (uint(a / b), a % b));
+            }
+        }
+    }
+}
+
+#[test]
+fn modular_arithmetic_matches_u128() {
+    let samples = samples();
+    let modules = [2u64, 3, 1_000_000_007, 0xffff_ffff_ffff_ffc5, u64::MAX];
+    for module in modules {
+        let ring = BigRing::new(uint(module as u128)).unwrap();
+        let m = module as u128;
+        for &a in &samples {
+            let x = ring.create_element(uint(a));
+            assert_eq!(*x.value(), uint(a % m));
+            assert_eq!(*(-x).value(), uint((m - a % m) % m));
+            for &b in samples.iter().step_by(7) {
+                let y = ring.create_element(uint(b));
+                let (a, b) = (a % m, b % m);
+                assert_eq!(*(x + y).value(), uint((a + b) % m));
+                assert_eq!(*(x - y).value(), uint((a + m - b) % m));
+                assert_eq!(*(x * y).value(), uint(a * b % m), "{a} * {b} mod {m}");
+            }
+            if let Some(inverse) = x.inverse() {
+                assert_eq!(x * inverse, ring.one());
+            }
+        }
+    }
+    assert!(BigRing::new(uint(1)).is_err());
+}
+
+#[test]
+fn parse_display_round_trip() {
+    for a in samples() {
+        let x = uint(a);
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:x}"), format!("{a:x}"));
+        assert_eq!(format!("{x:#x}"), format!("{a:#x}"));
+        assert_eq!(a.to_string().parse(), Ok(x));
+        assert_eq!(format!("{a:#x}").parse(), Ok(x));
+    }
+    let bn254 = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+    let r = *BigRing::bn254_scalar().module();
+    assert_eq!(r.to_string(), bn254);
+    assert_eq!(bn254.parse::<U256>(), Ok(r));
+    assert_eq!(
+        "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001".parse::<U256>(),
+        Ok(*BigRing::bls12_381_scalar().module())
+    );
+    assert_eq!("".parse::<U256>(), Err(ParseUintError::Empty));
+    assert_eq!("12a".parse::<U256>(), Err(ParseUintError::InvalidDigit));
+    assert_eq!(
+        "340282366920938463463374607431768211456".parse::<Uint<2>>(),
+        Err(ParseUintError::Overflow)
+    );
+}
+
+#[test]
+fn scalar_fields_are_fields() {
+    for ring in [BigRing::bn254_scalar(), BigRing::bls12_381_scalar()] {
+        let r = *ring.module();
+        let r_minus_one = r.overflowing_sub(&Uint::from(1)).0;
+        assert_eq!(ring.create_element(r), ring.zero());
+        assert_eq!(ring.create_element(r_minus_one), -ring.one());
+        let mut state = 7;
+        for _ in 0..8 {
+            let a = ring.create_element(Uint::from_limbs([(); 4].map(|_| next(&mut state))));
+            let inverse = a.inverse().unwrap();
+            assert_eq!(a * inverse, ring.one());
+            assert_eq!(a.pow(r_minus_one), ring.one(), "{a}^(r - 1) in {ring}");
+        }
+        assert_eq!(ring.zero().inverse(), None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn remainder_by_zero_panics() {
+    let ring = BigRing::bn254_scalar();
+    let _ = ring.one() % ring.zero();
+}
+
+#[test]
+#[should_panic(expected = "Ring operation failed")]
+fn remainder_panics_on_ring_mismatch() {
+    let _ = BigRing::bn254_scalar().one() % BigRing::bls12_381_scalar().one();
+}
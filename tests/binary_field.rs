@@ -0,0 +1,133 @@
+use zk_exam::{
+    binary::{BinaryField, BinaryFieldError},
+    field::Field,
+    ring::{Ring, RingElement},
+};
+
+const MAX_DEGREE: u32 = 8;
+
+/// Shift-and-add product reduced by `x^degree + module`, independent of the carry-less path
+fn reference_mul(a: u128, b: u128, degree: u32, module: u128) -> u128 {
+    let mut result = 0;
+    let mut a = a;
+    for i in 0..degree {
+        if (b >> i) & 1 == 1 {
+            result ^= a;
+        }
+        let carry = (a >> (degree - 1)) & 1 == 1;
+        a = (a << 1) & ((1 << degree) - 1);
+        if carry {
+            a ^= module;
+        }
+    }
+    result
+}
+
+/// Irreducible modules of the given degree by trial division over `GF(2)`
+fn reference_irreducible(degree: u32) -> Vec<u128> {
+    let divides = |d: u128, f: u128| {
+        let mut r = f;
+        let d_degree = 127 - d.leading_zeros();
+        while r != 0 && 127 - r.leading_zeros() >= d_degree {
+            r ^= d << (127 - r.leading_zeros() - d_degree);
+        }
+        r == 0
+    };
+    (0..1u128 << degree)
+        .filter(|module| {
+            let f = (1 << degree) | module;
+            (2..1u128 << (degree / 2 + 1)).all(|d| d == f || !divides(d, f))
+        })
+        .collect()
+}
+
+#[test]
+fn irreducibility_matches_trial_division() {
+    for degree in 1..=MAX_DEGREE {
+        let expected = reference_irreducible(degree);
+        let found: Vec<u128> = (0..1u128 << degree)
+            .filter(|module| BinaryField::new(degree, *module).is_ok())
+            .collect();
+        assert_eq!(found, expected, "degree {degree}");
+    }
+    assert_eq!(
+        BinaryField::new(2, 0b01),
+        Err(BinaryFieldError::Reducible {
+            degree: 2,
+            module: 0b01
+        })
+    );
+    assert!(BinaryField::new(0, 0).is_err());
+    assert!(BinaryField::new(129, 0).is_err());
+    assert!(BinaryField::new(4, 0x13).is_err());
+}
+
+#[test]
+fn arithmetic_matches_reference_for_all_pairs() {
+    for degree in 1..=MAX_DEGREE {
+        for module in reference_irreducible(degree) {
+            let field = BinaryField::new(degree, module).unwrap();
+            let size = 1u128 << degree;
+            for a in 0..size {
+                let x = field.create_element(a);
+                assert_eq!(-x, x);
+                for b in 0..size {
+                    let y = field.create_element(b);
+                    assert_eq!((x + y).value(), a ^ b);
+                    assert_eq!(x - y, x + y);
+                    assert_eq!(
+                        (x * y).value(),
+                        reference_mul(a, b, degree, module),
+                        "{a:#x} * {b:#x} in {field}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn every_nonzero_element_is_invertible() {
+    for degree in 1..=MAX_DEGREE {
+        for module in reference_irreducible(degree) {
+            let field = BinaryField::new(degree, module).unwrap();
+            assert_eq!(field.zero().inverse(), None);
+            for a in 1..1u128 << degree {
+                let x = field.create_element(a);
+                let inverse = x.inverse().unwrap();
+                assert_eq!(x * inverse, field.one(), "{a:#x} in {field}");
+                assert_eq!(x / x, field.one());
+                let order = x.multiplicative_order().unwrap();
+                assert!(((1u64 << degree) - 1).is_multiple_of(order));
+                assert_eq!(x.pow(order), field.one());
+                let root = x.sqrt().unwrap();
+                assert_eq!(root * root, x);
+            }
+        }
+    }
+}
+
+#[test]
+fn presets() {
+    let aes = BinaryField::aes();
+    // {53} * {ca} = {01} from FIPS-197
+    assert_eq!(aes.create_element(0x53).inv(), aes.create_element(0xca));
+    assert_eq!(
+        (aes.create_element(0x57) * aes.create_element(0x83)).value(),
+        0xc1
+    );
+    assert_eq!(aes.create_element(0x53).to_string(), "x^6 + x^4 + x + 1");
+
+    let ghash = BinaryField::ghash();
+    assert_eq!(ghash.cardinality(), None);
+    for value in [1, 2, 0x87, u128::MAX, 1 << 127, 0xdead_beef << 64] {
+        let x = ghash.create_element(value);
+        assert_eq!(x * x.inv(), ghash.one());
+        let root = x.sqrt().unwrap();
+        assert_eq!(root * root, x);
+    }
+    assert_eq!(
+        *BinaryField::with_smallest_module(8).unwrap().module(),
+        0x1b
+    );
+}
@@ -0,0 +1,70 @@
+use zk_exam::crt::{CrtError, solve_crt};
+
+fn solve(congruences: &[[i64; 2]]) -> Result<(i64, i64), CrtError> {
+    solve_crt(congruences).map(|(x, m, _)| (x, m))
+}
+
+/// Smallest non-negative solution by scanning `0..limit`
+fn brute_force(congruences: &[[i64; 2]], limit: i64) -> Option<i64> {
+    (0..limit).find(|x| congruences.iter().all(|[r, m]| (x - r).rem_euclid(*m) == 0))
+}
+
+#[test]
+fn coprime_modules() {
+    let (x, m, view) = solve_crt(&[[2, 3], [3, 5], [2, 7]]).unwrap();
+    assert_eq!((x, m), (23, 105));
+    assert_eq!(view.steps().len(), 2);
+    assert_eq!(solve(&[[-1, 4], [10, 9]]), Ok((19, 36)));
+    assert_eq!(solve(&[]), Ok((0, 1)));
+}
+
+#[test]
+fn non_coprime_consistent_modules() {
+    let (x, m, view) = solve_crt(&[[2, 4], [4, 6]]).unwrap();
+    assert_eq!((x, m), (10, 12));
+    assert_eq!(view.steps()[0].gcd, 2);
+    // 4 divides 8 and 3 divides 6, so one of the reduced modules is 1
+    assert_eq!(solve(&[[3, 4], [7, 8]]), Ok((7, 8)));
+    assert_eq!(solve(&[[5, 6], [2, 3]]), Ok((5, 6)));
+}
+
+#[test]
+fn matches_brute_force_for_small_systems() {
+    for m1 in 1..=12 {
+        for m2 in 1..=12 {
+            for r1 in 0..m1 {
+                for r2 in 0..m2 {
+                    let congruences = [[r1, m1], [r2, m2]];
+                    let expected = brute_force(&congruences, m1 * m2);
+                    match solve_crt(&congruences) {
+                        Ok((x, m, _)) => {
+                            assert_eq!(Some(x), expected, "{congruences:?}");
+                            assert_eq!(m, m1 * m2 / gcd(m1, m2));
+                        }
+                        Err(CrtError::Inconsistent { index: 1, gcd: g }) => {
+                            assert_eq!(expected, None, "{congruences:?}");
+                            assert_eq!(g, gcd(m1, m2));
+                        }
+                        Err(e) => panic!("{congruences:?}: {e}"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        solve(&[[1, 4], [2, 6]]),
+        Err(CrtError::Inconsistent { index: 1, gcd: 2 })
+    );
+    assert_eq!(solve(&[[1, 3], [1, 0]]), Err(CrtError::InvalidModule(0)));
+    assert_eq!(solve(&[[1, -5]]), Err(CrtError::InvalidModule(-5)));
+    let big = (1i64 << 62) - 57;
+    assert_eq!(solve(&[[1, big], [2, big - 2]]), Err(CrtError::Overflow));
+}
+
+fn gcd(x: i64, y: i64) -> i64 {
+    if y == 0 { x } else { gcd(y, x % y) }
+}
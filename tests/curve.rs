@@ -0,0 +1,109 @@
+use zk_exam::{
+    binary::BinaryField,
+    curve::{AffinePoint, Curve, CurveError, JacobianPoint},
+    ring::{Ring, SmallRing, SmallRingElement},
+};
+
+const MODULE: u32 = 97;
+
+/// `y^2 = x^3 + 2x + 3` over `F_97`
+fn toy_curve() -> Curve<SmallRingElement> {
+    let ring = SmallRing::new(MODULE).unwrap();
+    Curve::new(ring.create_element(2), ring.create_element(3)).unwrap()
+}
+
+/// All points by trying every pair of coordinates
+fn all_points(curve: &Curve<SmallRingElement>) -> Vec<AffinePoint<SmallRingElement>> {
+    let ring = SmallRing::new(MODULE).unwrap();
+    let mut points = vec![curve.infinity()];
+    for x in 0..MODULE as u64 {
+        for y in 0..MODULE as u64 {
+            if let Ok(point) = curve.point(ring.create_element(x), ring.create_element(y)) {
+                points.push(point);
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn scalar_multiplication_matches_repeated_addition() {
+    let curve = toy_curve();
+    let points = all_points(&curve);
+    let order = points.len() as u64;
+    for point in &points {
+        let mut multiple = curve.infinity();
+        for k in 0..=order + 1 {
+            assert_eq!(point.scalar_mul(k), multiple, "{k} * {point}");
+            let jacobian = point.to_jacobian().scalar_mul(k);
+            assert!(jacobian.is_on_curve());
+            assert_eq!(jacobian.to_affine(), Ok(multiple));
+            assert_eq!(jacobian, JacobianPoint::from(multiple));
+            multiple = multiple.checked_add(*point).unwrap();
+            assert!(multiple.is_on_curve());
+        }
+        // Lagrange: the point order divides the group order
+        assert!(point.scalar_mul(order).is_infinity());
+    }
+}
+
+#[test]
+fn affine_and_jacobian_laws_agree() {
+    let curve = toy_curve();
+    let points = all_points(&curve);
+    for p in &points {
+        assert_eq!(*p + curve.infinity(), *p);
+        assert!((*p - *p).is_infinity());
+        assert_eq!(p.double(), *p + *p);
+        assert_eq!(p.to_jacobian().double().to_affine(), Ok(p.double()));
+        for q in points.iter().step_by(5) {
+            let sum = *p + *q;
+            assert_eq!(sum, *q + *p);
+            assert_eq!((p.to_jacobian() + q.to_jacobian()).to_affine(), Ok(sum));
+            assert_eq!((p.to_jacobian() - q.to_jacobian()).to_affine(), Ok(*p - *q));
+            for r in points.iter().step_by(17) {
+                assert_eq!((*p + *q) + *r, *p + (*q + *r));
+            }
+        }
+    }
+}
+
+#[test]
+fn rejects_invalid_curves_and_points() {
+    let ring = SmallRing::new(MODULE).unwrap();
+    assert_eq!(
+        Curve::new(ring.zero(), ring.zero()),
+        Err(CurveError::Singular)
+    );
+    // 4 * (-3)^3 + 27 * 2^2 = 0
+    assert_eq!(
+        Curve::new(-ring.create_element(3), ring.create_element(2)),
+        Err(CurveError::Singular)
+    );
+    let curve = toy_curve();
+    assert_eq!(
+        curve.point(ring.create_element(0), ring.create_element(0)),
+        Err(CurveError::NotOnCurve)
+    );
+
+    let aes = BinaryField::aes();
+    assert_eq!(
+        Curve::new(aes.one(), aes.one()),
+        Err(CurveError::UnsupportedCharacteristic)
+    );
+    let f3 = SmallRing::new(3).unwrap();
+    assert_eq!(
+        Curve::new(f3.one(), f3.one()),
+        Err(CurveError::UnsupportedCharacteristic)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Curve operation failed")]
+fn addition_panics_on_curve_mismatch() {
+    let ring = SmallRing::new(MODULE).unwrap();
+    let other = Curve::new(ring.create_element(2), ring.create_element(4)).unwrap();
+    let p = all_points(&toy_curve())[1];
+    let q = all_points(&other)[1];
+    let _ = p + q;
+}
@@ -0,0 +1,135 @@
+use zk_exam::{
+    curve::{AffinePoint, Curve},
+    curve_group::{
+        count_points, count_points_naive, curve_group, find_prime_order_curve, point_order, points,
+        prime_subgroup,
+    },
+    extension::ExtensionField,
+    field::PrimeField,
+    params::SeededRng,
+    poly::Polynomial,
+    prime::is_prime,
+    ring::{Ring, SmallRing, SmallRingElement},
+};
+
+const PRIMES: [u32; 8] = [5, 7, 11, 13, 17, 19, 23, 29];
+
+/// All non-singular curves `y^2 = x^3 + ax + b` over `F_p`
+fn all_curves(p: u32) -> Vec<Curve<SmallRingElement>> {
+    let ring = SmallRing::new(p).unwrap();
+    let mut curves = vec![];
+    for a in 0..p as u64 {
+        for b in 0..p as u64 {
+            if let Ok(curve) = Curve::new(ring.create_element(a), ring.create_element(b)) {
+                curves.push(curve);
+            }
+        }
+    }
+    curves
+}
+
+/// Smallest `k > 0` with `kP = O` by repeated addition
+fn brute_force_order(point: &AffinePoint<SmallRingElement>) -> u64 {
+    let mut current = *point;
+    let mut k = 1;
+    while !current.is_infinity() {
+        current = current + *point;
+        k += 1;
+    }
+    k
+}
+
+#[test]
+fn count_points_matches_enumeration() {
+    for p in PRIMES {
+        for curve in all_curves(p) {
+            let order = count_points(&curve).unwrap();
+            assert_eq!(Some(order), count_points_naive(&curve), "{curve}");
+            // Hasse's bound |#E - p - 1| <= 2 sqrt(p)
+            let trace = order as i64 - p as i64 - 1;
+            assert!(trace * trace <= 4 * p as i64, "{curve}");
+        }
+    }
+}
+
+#[test]
+fn curve_group_structure() {
+    for p in [5, 7, 11, 13] {
+        for curve in all_curves(p) {
+            let group = curve_group(&curve).unwrap();
+            let points = points(&curve).unwrap();
+            let orders: Vec<u64> = points.iter().map(brute_force_order).collect();
+            assert_eq!(group.order, points.len() as u64);
+            assert_eq!(group.exponent, *orders.iter().max().unwrap(), "{curve}");
+            assert_eq!(brute_force_order(&group.generator), group.exponent);
+            let [n1, n2] = group.invariants();
+            assert!(
+                n1.is_multiple_of(n2) && (p as u64 - 1).is_multiple_of(n2),
+                "{curve}"
+            );
+            for (point, order) in points.iter().zip(orders) {
+                assert_eq!(point_order(point, group.order), order, "{point}");
+            }
+        }
+    }
+}
+
+#[test]
+fn prime_subgroup_generator_has_stated_order() {
+    for p in PRIMES {
+        for curve in all_curves(p) {
+            let subgroup = prime_subgroup(&curve).unwrap();
+            let largest = (2..=subgroup.curve_order)
+                .filter(|q| is_prime(*q) && subgroup.curve_order.is_multiple_of(*q))
+                .max()
+                .unwrap();
+            assert_eq!(subgroup.order, largest, "{curve}");
+            assert_eq!(subgroup.order * subgroup.cofactor, subgroup.curve_order);
+            assert_eq!(
+                brute_force_order(&subgroup.generator),
+                subgroup.order,
+                "{curve}"
+            );
+        }
+    }
+}
+
+#[test]
+fn prime_order_curves() {
+    for seed in 0..20 {
+        let mut rng = SeededRng::new(seed);
+        for p in [5, 11, 101, 1009] {
+            let ring = SmallRing::new(p).unwrap();
+            let (curve, order) = find_prime_order_curve(&ring, &mut rng).unwrap();
+            assert!(is_prime(order), "{curve}");
+            assert_eq!(count_points(&curve), Some(order), "{curve}");
+        }
+    }
+    let mut rng = SeededRng::new(0);
+    for module in [2, 3, 15, 49] {
+        let ring = SmallRing::new(module).unwrap();
+        assert!(
+            find_prime_order_curve(&ring, &mut rng).is_none(),
+            "{module}"
+        );
+    }
+}
+
+#[test]
+fn other_rings_are_rejected() {
+    // Z/35Z is not a field
+    let ring = SmallRing::new(35).unwrap();
+    let curve = Curve::new(ring.one(), ring.one()).unwrap();
+    assert_eq!(count_points(&curve), None);
+    assert!(points(&curve).is_none());
+
+    // GF(49) = F_7[x] / (x^2 + 1): multiples of one only cover F_7
+    let base = PrimeField::new(7).unwrap();
+    let module = Polynomial::new(vec![base.one(), base.zero(), base.one()]);
+    let field = ExtensionField::<_, 2>::new(&module).unwrap();
+    let curve = Curve::new(field.one(), field.one()).unwrap();
+    assert_eq!(count_points(&curve), None);
+    assert_eq!(count_points_naive(&curve), None);
+    assert!(curve_group(&curve).is_none());
+    assert!(prime_subgroup(&curve).is_none());
+}
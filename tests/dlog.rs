@@ -0,0 +1,93 @@
+use zk_exam::{
+    dlog::{baby_step_giant_step, baby_step_giant_step_with_view, pohlig_hellman, pollard_rho_log},
+    group::{Group, Multiplicative},
+    prime::is_prime,
+    ring::{Ring, SmallRing, SmallRingElement},
+};
+
+fn unit(ring: &SmallRing, value: u64) -> Option<Multiplicative<SmallRingElement>> {
+    Multiplicative::new(ring.create_element(value))
+}
+
+/// Smallest `x` with `g^x = h` by scanning all powers
+fn brute_force_log<G: Group>(g: &G, h: &G, order: u64) -> Option<u64> {
+    let mut power = g.identity();
+    for x in 0..order {
+        if power == *h {
+            return Some(x);
+        }
+        power = power.operate(g);
+    }
+    None
+}
+
+#[test]
+fn solvers_match_brute_force() {
+    // Prime, smooth (1025 - 1 = 2^10) and composite modules
+    for module in [101, 1009, 257, 1025, 35, 4096] {
+        let ring = SmallRing::new(module).unwrap();
+        let step = module as usize / 40 + 1;
+        for g in (2..module as u64)
+            .filter_map(|g| unit(&ring, g))
+            .step_by(step)
+        {
+            let order = g.order().unwrap();
+            for h in (1..module as u64)
+                .filter_map(|h| unit(&ring, h))
+                .step_by(step)
+            {
+                let expected = brute_force_log(&g, &h, order);
+                assert_eq!(
+                    baby_step_giant_step(&g, &h, order),
+                    expected,
+                    "{g}^x = {h} mod {module}"
+                );
+                assert_eq!(
+                    pohlig_hellman(&g, &h, order),
+                    expected,
+                    "{g}^x = {h} mod {module}"
+                );
+                let rho = pollard_rho_log(&g, &h, order);
+                if is_prime(order) || rho.is_some() {
+                    assert_eq!(rho, expected, "{g}^x = {h} mod {module}");
+                }
+                // A multiple of the order works as well
+                assert_eq!(baby_step_giant_step(&g, &h, order * 3), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn degenerate_orders() {
+    let ring = SmallRing::new(101).unwrap();
+    let one = unit(&ring, 1).unwrap();
+    let g = unit(&ring, 2).unwrap();
+    for solver in [baby_step_giant_step, pollard_rho_log, pohlig_hellman] {
+        assert_eq!(solver(&one, &one, 1), Some(0));
+        assert_eq!(solver(&one, &g, 1), None);
+        assert_eq!(solver(&g, &g, 0), None);
+    }
+    // 4 = 2^2 generates the squares, 2 is not one of them
+    let square = unit(&ring, 4).unwrap();
+    assert_eq!(baby_step_giant_step(&square, &g, 50), None);
+    assert_eq!(pohlig_hellman(&square, &g, 50), None);
+    assert_eq!(pollard_rho_log(&square, &g, 50), None);
+}
+
+#[test]
+fn view_records_the_match() {
+    let ring = SmallRing::new(101).unwrap();
+    let g = unit(&ring, 2).unwrap();
+    let h = g.pow(77u64);
+    let (x, view) = baby_step_giant_step_with_view(&g, &h, 100);
+    assert_eq!(x, Some(77));
+    assert_eq!(view.m(), 10);
+    assert_eq!(view.baby_steps().len(), 10);
+    assert_eq!(view.solution(), Some([7, 7]));
+    assert_eq!(view.giant_steps().len(), 8);
+    assert!(
+        view.to_string()
+            .ends_with("x = i * m + j = 7 * 10 + 7 = 77\n")
+    );
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use zk_exam::{
+    extension::{ExtensionError, ExtensionField, ExtensionFieldElement, is_irreducible},
+    field::{Field, PrimeField, PrimeFieldElement},
+    poly::Polynomial,
+    ring::{Ring, RingElement},
+};
+
+fn poly(field: &PrimeField, coefficients: &[u64]) -> Polynomial<PrimeFieldElement> {
+    Polynomial::new(
+        coefficients
+            .iter()
+            .map(|c| field.create_element(*c))
+            .collect(),
+    )
+}
+
+/// All polynomials of degree below `len`, coefficients as base `p` digits
+fn all_polys(field: &PrimeField, len: u32) -> Vec<Polynomial<PrimeFieldElement>> {
+    let p = *field.module() as u64;
+    (0..p.pow(len))
+        .map(|mut index| {
+            let digits: Vec<u64> = (0..len)
+                .map(|_| {
+                    let digit = index % p;
+                    index /= p;
+                    digit
+                })
+                .collect();
+            poly(field, &digits)
+        })
+        .collect()
+}
+
+fn all_elements<const K: usize>(
+    field: &ExtensionField<PrimeFieldElement, K>,
+    base: &PrimeField,
+) -> Vec<ExtensionFieldElement<PrimeFieldElement, K>> {
+    all_polys(base, K as u32)
+        .iter()
+        .map(|poly| field.from_polynomial(poly))
+        .collect()
+}
+
+fn check_field<const K: usize>(base: &PrimeField, module: &[u64]) {
+    let field = ExtensionField::<_, K>::new(&poly(base, module)).unwrap();
+    let q = field.cardinality().unwrap();
+    let elements = all_elements(&field, base);
+    assert_eq!(elements.len() as u64, q);
+    assert_eq!(elements.iter().collect::<HashSet<_>>().len() as u64, q);
+
+    let squares: HashSet<_> = elements.iter().map(|x| *x * *x).collect();
+    assert_eq!(squares.len() as u64, q.div_ceil(2));
+    for x in &elements {
+        for y in elements.iter().step_by(3) {
+            let product = (x.to_polynomial() * y.to_polynomial())
+                .div_rem(&field.module_polynomial())
+                .unwrap()
+                .1;
+            assert_eq!((*x * *y).to_polynomial(), product, "{x} * {y} in {field}");
+        }
+        match x.sqrt() {
+            Some(root) => assert_eq!(root * root, *x, "sqrt({x}) in {field}"),
+            None => assert!(!squares.contains(x), "{x} is a square in {field}"),
+        }
+        assert_eq!(x.legendre() == -1, !squares.contains(x));
+        if *x == field.zero() {
+            assert_eq!(x.inverse(), None);
+            continue;
+        }
+        assert_eq!(*x * x.inv(), field.one(), "{x}^-1 in {field}");
+        let order = x.multiplicative_order().unwrap();
+        assert!((q - 1).is_multiple_of(order));
+        assert_eq!(x.pow(order), field.one());
+    }
+    assert_eq!(
+        field.generator().pow(q),
+        field.generator(),
+        "Frobenius fixes F_q"
+    );
+}
+
+#[test]
+fn gf_49_is_a_field() {
+    // -1 is a non-residue modulo 7, so x^2 + 1 is irreducible
+    check_field::<2>(&PrimeField::new(7).unwrap(), &[1, 0, 1]);
+}
+
+#[test]
+fn gf_27_is_a_field() {
+    check_field::<3>(&PrimeField::new(3).unwrap(), &[1, 2, 0, 1]);
+}
+
+#[test]
+fn rabin_test_counts_irreducible_polynomials() {
+    // Number of monic irreducible polynomials of degree n over F_p
+    for (p, n, expected) in [(3, 2, 3), (3, 3, 8), (3, 4, 18), (2, 5, 6), (7, 2, 21)] {
+        let base = PrimeField::new(p).unwrap();
+        let found = all_polys(&base, n)
+            .into_iter()
+            .map(|lower| &lower + &Polynomial::monomial(base.one(), n as usize))
+            .filter(is_irreducible)
+            .count();
+        assert_eq!(found, expected, "degree {n} over F_{p}");
+    }
+}
+
+#[test]
+fn rejects_reducible_modules() {
+    let base = PrimeField::new(7).unwrap();
+    // x^2 - 1 = (x - 1)(x + 1)
+    assert_eq!(
+        ExtensionField::<_, 2>::new(&poly(&base, &[6, 0, 1])),
+        Err(ExtensionError::Reducible)
+    );
+    // -3 = 4 = 2^2 is a square modulo 7
+    assert_eq!(
+        ExtensionField::<_, 2>::new(&poly(&base, &[3, 0, 1])),
+        Err(ExtensionError::Reducible)
+    );
+    assert_eq!(
+        ExtensionField::<_, 2>::new(&poly(&base, &[1, 0, 0, 1])),
+        Err(ExtensionError::InvalidDegree {
+            expected: 2,
+            found: Some(3)
+        })
+    );
+}
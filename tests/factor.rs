@@ -0,0 +1,78 @@
+use zk_exam::{
+    factor::{factorize, factorize_with_view, pollard_p_minus_one, pollard_rho, trial_division},
+    prime::is_prime,
+};
+
+fn check(n: u64) {
+    let factorization = factorize(n);
+    assert_eq!(factorization.value(), n, "{n} = {factorization}");
+    let primes: Vec<u64> = factorization.primes().collect();
+    assert!(primes.iter().all(|p| is_prime(*p)), "{n} = {factorization}");
+    assert!(primes.windows(2).all(|w| w[0] < w[1]));
+    assert!(factorization.factors().iter().all(|(_, e)| *e > 0));
+}
+
+#[test]
+fn factorization_round_trips() {
+    for n in 1..20_000 {
+        check(n);
+    }
+    for n in [
+        999983 * 999983,
+        1000003 * 1000033,
+        (1 << 31) - 1,
+        ((1 << 31) - 1) * ((1 << 31) - 1),
+        2u64.pow(10) * 3 * 1000003 * 97,
+        18446744073709551557,
+        u64::MAX,
+        4294967291 * 4294967279,
+        600851475143,
+    ] {
+        check(n);
+    }
+    assert_eq!(factorize(1).to_string(), "1");
+    assert_eq!(factorize(360).to_string(), "2^3 * 3^2 * 5");
+    assert_eq!(factorize(360).factors(), [(2, 3), (3, 2), (5, 1)]);
+}
+
+#[test]
+fn view_is_short() {
+    for n in [999983 * 999983, 1000003 * 1000033, 4294967291 * 4294967279] {
+        let (factorization, view) = factorize_with_view(n);
+        assert_eq!(factorization, factorize(n));
+        assert!(view.to_string().lines().count() < 50, "{view}");
+    }
+}
+
+#[test]
+fn trial_division_stops_at_bound() {
+    let (factorization, rest) = trial_division(2 * 2 * 3 * 1009, 1000);
+    assert_eq!(factorization.factors(), [(2, 2), (3, 1)]);
+    assert_eq!(rest, 1009);
+    assert_eq!(trial_division(97, 1000).1, 97);
+    let (factorization, rest) = trial_division(999983 * 1000003, u64::MAX);
+    assert_eq!(factorization.factors(), [(999983, 1)]);
+    assert_eq!(rest, 1000003);
+    assert_eq!(trial_division(1, 100).1, 1);
+}
+
+#[test]
+fn pollard_methods_find_factors() {
+    for n in [8051, 10403, 455459, 1000003 * 1000033, 999983 * 999983] {
+        let d = (1..).find_map(|c| pollard_rho(n, c)).unwrap();
+        assert!(d > 1 && d < n && n.is_multiple_of(d), "{d} | {n}");
+    }
+    assert_eq!(pollard_rho(1000, 1), Some(2));
+    // 2^4 * 3 * 5 * 7 * 11 + 1 is prime and its p - 1 is 11-smooth
+    let p = 2u64.pow(4) * 3 * 5 * 7 * 11 + 1;
+    assert!(is_prime(p));
+    let n = p * 1000003;
+    let d = pollard_p_minus_one(n, 20).unwrap();
+    assert_eq!(d, p);
+    // 1000003 - 1 = 2 * 3 * 166667, not 20-smooth
+    assert_eq!(pollard_p_minus_one(1000003 * 1000033, 20), None);
+    assert_eq!(pollard_p_minus_one(0, 100), None);
+    assert_eq!(pollard_p_minus_one(1, 100), None);
+    assert_eq!(pollard_rho(0, 1), None);
+    assert_eq!(pollard_rho(1, 1), None);
+}
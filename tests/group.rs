@@ -0,0 +1,80 @@
+use zk_exam::{
+    bigint::{BigRing, Uint},
+    curve::Curve,
+    extension::ExtensionField,
+    field::{Field, PrimeField},
+    group::{Additive, Group, Multiplicative},
+    poly::Polynomial,
+    ring::{Ring, RingElement, SmallRing},
+};
+
+/// Smallest `k > 0` with `x^k = 1` by repeated operation
+fn brute_force_order<G: Group>(x: &G) -> u64 {
+    let mut current = *x;
+    let mut k = 1;
+    while !current.is_identity() {
+        current = current.operate(x);
+        k += 1;
+    }
+    k
+}
+
+#[test]
+fn ring_group_orders_match_brute_force() {
+    for module in [2, 12, 36, 97] {
+        let ring = SmallRing::new(module).unwrap();
+        for value in 0..module as u64 {
+            let x = ring.create_element(value);
+            let additive = Additive::new(x);
+            assert_eq!(additive.order(), Some(brute_force_order(&additive)));
+            assert_eq!(additive.inverse().operate(&additive), additive.identity());
+            match Multiplicative::new(x) {
+                Some(unit) => {
+                    assert_eq!(unit.order(), Some(brute_force_order(&unit)));
+                    assert_eq!(unit.pow(unit.order().unwrap()), unit.identity());
+                }
+                None => assert!(x.inverse().is_none()),
+            }
+        }
+    }
+    let unit = Multiplicative::new(BigRing::bn254_scalar().create_element(Uint::from(5))).unwrap();
+    assert_eq!(unit.order(), None);
+}
+
+#[test]
+fn point_orders_over_prime_fields() {
+    let ring = SmallRing::new(97).unwrap();
+    let curve = Curve::new(ring.create_element(2), ring.create_element(3)).unwrap();
+    for x in 0..97 {
+        let x = ring.create_element(x);
+        let Some(y) = curve.rhs(&x).sqrt() else {
+            continue;
+        };
+        let point = curve.point(x, y).unwrap();
+        assert_eq!(point.order(), Some(brute_force_order(&point)), "{point}");
+    }
+    assert_eq!(curve.infinity().order(), Some(1));
+}
+
+#[test]
+fn point_orders_are_unknown_over_other_fields() {
+    let bn254 = BigRing::bn254_scalar();
+    let curve = Curve::new(bn254.one(), -bn254.one()).unwrap();
+    let point = curve.point(bn254.one(), bn254.one()).unwrap();
+    assert_eq!(point.order(), None);
+
+    // GF(49) = F_7[x] / (x^2 + 1)
+    let base = PrimeField::new(7).unwrap();
+    let module = Polynomial::new(vec![base.one(), base.zero(), base.one()]);
+    let field = ExtensionField::<_, 2>::new(&module).unwrap();
+    let curve = Curve::new(field.one(), field.one()).unwrap();
+    let x = field.generator();
+    let point = (0..7)
+        .find_map(|c| {
+            let x = x + field.from_base(base.create_element(c));
+            curve.point(x, curve.rhs(&x).sqrt()?).ok()
+        })
+        .unwrap();
+    assert_eq!(point.order(), None);
+    assert_eq!(point.pow(brute_force_order(&point)), curve.infinity());
+}
@@ -0,0 +1,88 @@
+use zk_exam::{
+    montgomery::MontgomeryRing,
+    ring::{Ring, RingElement, RingError},
+};
+
+const MODULES: [u64; 6] = [
+    3,
+    15,
+    97,
+    4294967291,
+    1_000_000_000_000_000_003,
+    (1 << 63) - 25,
+];
+
+fn samples(module: u64) -> Vec<u64> {
+    let mut samples = vec![0, 1, 2, module - 1, module, module + 1, u64::MAX];
+    let mut value: u64 = 0x9e3779b97f4a7c15;
+    for _ in 0..64 {
+        value = value
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        samples.push(value);
+    }
+    samples
+}
+
+#[test]
+fn round_trips_through_montgomery_form() {
+    for module in MODULES {
+        let ring = MontgomeryRing::new(module).unwrap();
+        for value in samples(module) {
+            let x = ring.to_montgomery(value);
+            assert_eq!(x.value(), value % module);
+            // x * 2^64 (mod module)
+            assert_eq!(
+                x.montgomery_value() as u128,
+                ((value as u128) << 64) % module as u128
+            );
+            assert_eq!(x.to_string(), (value % module).to_string());
+        }
+    }
+}
+
+#[test]
+fn arithmetic_matches_remainder() {
+    for module in MODULES {
+        let ring = MontgomeryRing::new(module).unwrap();
+        let m = module as u128;
+        let samples = samples(module);
+        for &a in &samples {
+            let x = ring.create_element(a);
+            let a = a as u128 % m;
+            assert_eq!((-x).value() as u128, (m - a) % m);
+            for &b in &samples {
+                let y = ring.create_element(b);
+                let b = b as u128 % m;
+                assert_eq!((x + y).value() as u128, (a + b) % m);
+                assert_eq!((x - y).value() as u128, (a + m - b) % m);
+                assert_eq!((x * y).value() as u128, a * b % m, "{a} * {b} mod {m}");
+            }
+            match x.inverse() {
+                Some(inverse) => assert_eq!(inverse.value() as u128 * a % m, 1),
+                None => assert!(a == 0 || module == 15),
+            }
+        }
+    }
+}
+
+#[test]
+fn rejects_unsupported_modules() {
+    assert_eq!(MontgomeryRing::new(1), Err(RingError::InvalidModule(1)));
+    assert_eq!(
+        MontgomeryRing::new(10),
+        Err(RingError::UnsupportedModule(10))
+    );
+    assert_eq!(
+        MontgomeryRing::new((1 << 63) + 1),
+        Err(RingError::UnsupportedModule((1 << 63) + 1))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Ring operation failed")]
+fn operators_panic_on_ring_mismatch() {
+    let x = MontgomeryRing::new(5).unwrap().one();
+    let y = MontgomeryRing::new(7).unwrap().one();
+    let _ = x * y;
+}
@@ -0,0 +1,89 @@
+use zk_exam::{
+    ntt::{inverse_ntt, multiply, ntt, root_of_unity},
+    poly::Polynomial,
+    ring::{Ring, RingElement, SmallRing, SmallRingElement},
+};
+
+/// `119 * 2^23 + 1`
+const MODULE: u32 = 998244353;
+
+fn random_poly(ring: &SmallRing, len: usize, seed: u64) -> Polynomial<SmallRingElement> {
+    let mut state = seed;
+    Polynomial::new(
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                ring.create_element(state >> 33)
+            })
+            .collect(),
+    )
+}
+
+fn schoolbook(
+    a: &Polynomial<SmallRingElement>,
+    b: &Polynomial<SmallRingElement>,
+    ring: &SmallRing,
+) -> Polynomial<SmallRingElement> {
+    let mut coefficients = vec![ring.zero(); a.coefficients().len() + b.coefficients().len() - 1];
+    for (i, x) in a.coefficients().iter().enumerate() {
+        for (j, y) in b.coefficients().iter().enumerate() {
+            coefficients[i + j] = coefficients[i + j] + *x * *y;
+        }
+    }
+    Polynomial::new(coefficients)
+}
+
+#[test]
+fn large_products_match_schoolbook() {
+    let ring = SmallRing::new(MODULE).unwrap();
+    for (len_a, len_b) in [(64, 64), (64, 100), (129, 255), (300, 70)] {
+        let a = random_poly(&ring, len_a, len_a as u64);
+        let b = random_poly(&ring, len_b, 1000 + len_b as u64);
+        let expected = schoolbook(&a, &b, &ring);
+        assert_eq!(multiply(&a, &b), Some(expected.clone()));
+        assert_eq!(&a * &b, expected, "{len_a} x {len_b}");
+    }
+    // No root of unity of order 128 modulo 97, multiplication falls back to schoolbook
+    let small = SmallRing::new(97).unwrap();
+    let (a, b) = (random_poly(&small, 64, 1), random_poly(&small, 64, 2));
+    assert_eq!(multiply(&a, &b), None);
+    assert_eq!(&a * &b, schoolbook(&a, &b, &small));
+}
+
+#[test]
+fn inverse_transform_round_trips() {
+    let ring = SmallRing::new(MODULE).unwrap();
+    for log_n in 0..=10 {
+        let n = 1usize << log_n;
+        let root = root_of_unity(&ring, log_n).unwrap();
+        let original = random_poly(&ring, n, log_n as u64).coefficients().to_vec();
+        let mut values = original.clone();
+        values.resize(n, ring.zero());
+        let padded = values.clone();
+        ntt(&mut values, root);
+        // values[i] = f(root^i)
+        let poly = Polynomial::new(original);
+        for (i, value) in values.iter().enumerate().step_by(n.div_ceil(16)) {
+            assert_eq!(*value, poly.evaluate(&root.pow(i as u64)));
+        }
+        inverse_ntt(&mut values, root);
+        assert_eq!(values, padded);
+    }
+}
+
+#[test]
+fn roots_of_unity_have_exact_order() {
+    let ring = SmallRing::new(MODULE).unwrap();
+    for log_n in 0..=23 {
+        let root = root_of_unity(&ring, log_n).unwrap();
+        assert_eq!(root.multiplicative_order(), Some(1 << log_n));
+    }
+    assert_eq!(root_of_unity(&ring, 24), None);
+    assert_eq!(root_of_unity(&SmallRing::new(15).unwrap(), 1), None);
+    assert_eq!(
+        root_of_unity(&SmallRing::new(2).unwrap(), 0),
+        Some(SmallRing::new(2).unwrap().one())
+    );
+}
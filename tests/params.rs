@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use zk_exam::{
+    curve_group::count_points,
+    params::{
+        CrtConstraints, ParamsError, SeededRng, crt_params, ecdlp_params, euclid_params,
+        inverse_params, shamir_params,
+    },
+    prime::is_prime,
+    ring::{Ring, RingElement},
+    shamir::reconstruct,
+};
+
+const SEEDS: u64 = 200;
+
+fn gcd(x: u64, y: u64) -> u64 {
+    if y == 0 { x } else { gcd(y, x % y) }
+}
+
+#[test]
+fn seeded_rng_is_reproducible() {
+    let sequence = |mut rng: SeededRng| (0..16).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    assert_eq!(sequence(SeededRng::new(42)), sequence(SeededRng::new(42)));
+    assert_ne!(sequence(SeededRng::new(42)), sequence(SeededRng::new(43)));
+    // SplitMix64 reference output for seed 0
+    assert_eq!(SeededRng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+
+    let streams: HashSet<Vec<u64>> = (0..4)
+        .flat_map(|student| (0..4).map(move |task| (student, task)))
+        .map(|(student, task)| sequence(SeededRng::for_task(7, student, task)))
+        .collect();
+    assert_eq!(streams.len(), 16);
+    assert_eq!(
+        sequence(SeededRng::for_task(7, 3, 2)),
+        sequence(SeededRng::for_task(7, 3, 2))
+    );
+}
+
+#[test]
+fn gen_range_stays_in_bounds() {
+    let mut rng = SeededRng::new(1);
+    for (start, end) in [(0, 0), (5, 5), (0, 1), (3, 10), (100, 1000), (0, u64::MAX)] {
+        let mut seen = HashSet::new();
+        for _ in 0..1000 {
+            let value = rng.gen_range(start..=end).unwrap();
+            assert!((start..=end).contains(&value));
+            seen.insert(value);
+        }
+        if end - start < 10 {
+            assert_eq!(seen.len() as u64, end - start + 1, "{start}..={end}");
+        }
+    }
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = rng.gen_range(5..=4);
+    assert_eq!(empty, Err(ParamsError::EmptyRange));
+}
+
+#[test]
+fn crt_params_satisfy_constraints() {
+    let constraints = CrtConstraints::default();
+    for seed in 0..SEEDS {
+        let (congruences, answer) = crt_params(&mut SeededRng::new(seed), &constraints).unwrap();
+        assert_eq!(congruences.len(), constraints.count);
+        assert!(constraints.answer.contains(&answer));
+        let modules: Vec<u64> = congruences.iter().map(|[_, m]| *m as u64).collect();
+        for (i, m) in modules.iter().enumerate() {
+            assert!(constraints.modules.contains(m));
+            assert!(modules[..i].iter().all(|other| gcd(*other, *m) == 1));
+        }
+        assert!(answer < modules.iter().product());
+        for [r, m] in congruences {
+            assert_eq!(r as u64, answer % m as u64);
+        }
+    }
+    let impossible = CrtConstraints {
+        count: 3,
+        modules: 4..=4,
+        answer: 0..=10,
+    };
+    assert!(matches!(
+        crt_params(&mut SeededRng::new(0), &impossible),
+        Err(ParamsError::Unsatisfiable(_))
+    ));
+}
+
+#[test]
+fn euclid_and_inverse_params_satisfy_constraints() {
+    for seed in 0..SEEDS {
+        let mut rng = SeededRng::new(seed);
+        let (x, y) = euclid_params(&mut rng, 2..=100, true).unwrap();
+        assert!(x >= 2 && y >= 2 && x != y && x <= 100 && y <= 100);
+        assert_eq!(gcd(x as u64, y as u64), 1);
+
+        let element = inverse_params(&mut rng, 5..=60).unwrap();
+        assert!((5..=60).contains(&element.module()));
+        assert_ne!(element, element.ring().one());
+        assert_eq!(element * element.inverse().unwrap(), element.ring().one());
+    }
+}
+
+#[test]
+fn shamir_params_recover_the_secret() {
+    for seed in 0..SEEDS {
+        let (shares, secret) = shamir_params(&mut SeededRng::new(seed), 11..=97, 3).unwrap();
+        assert_eq!(shares.len(), 3);
+        assert!(is_prime(secret.module() as u64));
+        let xs: HashSet<_> = shares.iter().map(|share| share.x).collect();
+        assert_eq!(xs.len(), 3);
+        assert_eq!(reconstruct(&shares, 3).unwrap().0, secret);
+    }
+}
+
+#[test]
+fn ecdlp_params_satisfy_constraints() {
+    // Tiny fields include curves of order 2 and 3
+    for (seed, modules) in (0..SEEDS).zip([5..=7, 5..=13, 11..=101].into_iter().cycle()) {
+        let (p, q, order, k) = ecdlp_params(&mut SeededRng::new(seed), modules.clone()).unwrap();
+        let module = p.curve().a().module() as u64;
+        assert!(modules.contains(&(module as u32)) && is_prime(module));
+        assert_eq!(count_points(p.curve()), Some(order));
+        assert!(is_prime(order) && order >= 5);
+        assert!((2..order).contains(&k));
+        assert!(!p.is_infinity());
+        assert_eq!(p.scalar_mul(k), q);
+    }
+}
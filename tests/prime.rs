@@ -0,0 +1,106 @@
+use zk_exam::{
+    bigint::Uint,
+    params::{ParamsError, SeededRng},
+    prime::{
+        is_prime, is_probable_prime, random_big_prime, random_prime, random_prime_with_residue,
+        random_safe_prime,
+    },
+};
+
+const SIEVE_LIMIT: usize = 100_000;
+
+fn sieve() -> Vec<bool> {
+    let mut is_prime = vec![true; SIEVE_LIMIT];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    for i in 2..SIEVE_LIMIT {
+        if is_prime[i] {
+            for j in (i * i..SIEVE_LIMIT).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+    }
+    is_prime
+}
+
+fn bit_length(n: u64) -> u32 {
+    u64::BITS - n.leading_zeros()
+}
+
+#[test]
+fn is_prime_matches_sieve() {
+    for (n, expected) in sieve().into_iter().enumerate() {
+        assert_eq!(is_prime(n as u64), expected, "{n}");
+    }
+}
+
+#[test]
+fn is_prime_rejects_pseudoprimes() {
+    let carmichael = [
+        561, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265, 321197185,
+    ];
+    // Strong pseudoprimes to all prime bases up to 7 and up to 23
+    let strong = [3215031751, 3825123056546413051];
+    for n in carmichael.into_iter().chain(strong) {
+        assert!(!is_prime(n), "{n}");
+    }
+    // Mersenne prime 2^61 - 1, largest primes below 2^63 and 2^64
+    for p in [(1 << 61) - 1, (1 << 63) - 25, 18446744073709551557] {
+        assert!(is_prime(p), "{p}");
+        assert!(!is_prime(p - 2));
+    }
+    assert!(!is_prime(u64::MAX));
+}
+
+#[test]
+fn is_probable_prime_on_big_values() {
+    let mut rng = SeededRng::new(0);
+    let mersenne: Uint<2> = Uint::from_limbs([u64::MAX, u64::MAX >> 1]);
+    assert!(is_probable_prime(&mersenne, 20, &mut rng));
+    // (2^61 - 1) * (2^64 - 59)
+    let product = ((1u128 << 61) - 1) * 18446744073709551557;
+    let composite: Uint<2> = Uint::from_limbs([product as u64, (product >> 64) as u64]);
+    assert!(!is_probable_prime(&composite, 20, &mut rng));
+}
+
+#[test]
+fn random_primes_have_requested_shape() {
+    let mut rng = SeededRng::new(1);
+    for bits in 2..=64 {
+        for _ in 0..8 {
+            let p = random_prime(&mut rng, bits).unwrap();
+            assert!(is_prime(p) && bit_length(p) == bits, "{p} of {bits} bits");
+        }
+    }
+    for bits in 3..=40 {
+        let p = random_safe_prime(&mut rng, bits).unwrap();
+        assert!(is_prime(p) && is_prime((p - 1) / 2), "{p}");
+        assert_eq!(bit_length(p), bits);
+    }
+    for (residue, module) in [(3, 4), (1, 4), (1, 8), (5, 12), (1, 1 << 10)] {
+        for bits in bit_length(module) + 6..=64 {
+            let p = random_prime_with_residue(&mut rng, bits, residue, module).unwrap();
+            assert!(is_prime(p) && bit_length(p) == bits);
+            assert_eq!(p % module, residue);
+        }
+    }
+    for bits in [65, 100, 128] {
+        let p: Uint<2> = random_big_prime(&mut rng, bits, 20).unwrap();
+        assert_eq!(p.bits(), bits);
+        assert!(is_probable_prime(&p, 20, &mut rng));
+    }
+}
+
+#[test]
+fn rejects_invalid_bit_lengths() {
+    let mut rng = SeededRng::new(2);
+    let invalid = Err(ParamsError::Unsatisfiable("prime bit length"));
+    assert_eq!(random_prime(&mut rng, 1), invalid);
+    assert_eq!(random_prime(&mut rng, 65), invalid);
+    assert_eq!(random_safe_prime(&mut rng, 2), invalid);
+    assert_eq!(random_prime_with_residue(&mut rng, 0, 1, 4), invalid);
+    assert_eq!(
+        random_big_prime::<1>(&mut rng, 65, 10),
+        invalid.map(Uint::from)
+    );
+}
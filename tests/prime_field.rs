@@ -0,0 +1,43 @@
+use zk_exam::{
+    field::{Field, PrimeField},
+    ring::{Ring, RingError},
+};
+
+#[test]
+fn arithmetic_matches_integers() {
+    for p in [2u32, 3, 7, 11, 31] {
+        let field = PrimeField::new(p).unwrap();
+        let n = p as i64;
+        for a in 0..n {
+            let x = field.create_element(a as u64);
+            for b in 0..n {
+                let y = field.create_element(b as u64);
+                assert_eq!((x + y).value() as i64, (a + b) % n);
+                assert_eq!((x - y).value() as i64, (a - b).rem_euclid(n));
+                assert_eq!((x * y).value() as i64, a * b % n);
+                if b != 0 {
+                    assert_eq!((x % y).value() as i64, a % b, "{a} % {b} mod {n}");
+                    assert_eq!(x / y * y, x);
+                }
+            }
+            if a != 0 {
+                assert_eq!(x * x.inv(), field.one());
+            }
+        }
+    }
+    assert_eq!(PrimeField::new(12), Err(RingError::NotPrime(12)));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn remainder_by_zero_panics() {
+    let field = PrimeField::new(7).unwrap();
+    let _ = field.one() % field.zero();
+}
+
+#[test]
+#[should_panic(expected = "Field operation failed")]
+fn remainder_panics_on_field_mismatch() {
+    let _ = PrimeField::new(7).unwrap().create_element(5)
+        % PrimeField::new(11).unwrap().create_element(3);
+}
@@ -0,0 +1,112 @@
+use zk_exam::{
+    field::PrimeField,
+    prime::is_prime,
+    quadratic::{cipolla, jacobi, legendre, sqrt_mod, tonelli_shanks},
+    ring::Ring,
+};
+
+const LIMIT: u64 = 300;
+
+fn is_square(a: u64, n: u64) -> bool {
+    (0..n).any(|x| x * x % n == a % n)
+}
+
+/// Jacobi symbol as the product of Legendre symbols over the prime factors of `n`
+fn jacobi_by_factors(a: u64, mut n: u64) -> i8 {
+    let mut result = 1;
+    let mut p = 3;
+    while n > 1 {
+        while n.is_multiple_of(p) {
+            n /= p;
+            result *= match a % p {
+                0 => 0,
+                r if is_square(r, p) => 1,
+                _ => -1,
+            };
+        }
+        p += 2;
+    }
+    result
+}
+
+#[test]
+fn legendre_matches_squares() {
+    for p in (3..LIMIT).filter(|p| is_prime(*p)) {
+        for a in 0..2 * p {
+            let expected = match a % p {
+                0 => 0,
+                r if is_square(r, p) => 1,
+                _ => -1,
+            };
+            assert_eq!(legendre(a, p), expected, "({a}/{p})");
+        }
+    }
+}
+
+#[test]
+fn jacobi_matches_factorization() {
+    for n in (1..LIMIT).step_by(2) {
+        for a in 0..n {
+            assert_eq!(jacobi(a, n), jacobi_by_factors(a, n), "({a}/{n})");
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn legendre_rejects_two() {
+    legendre(1, 2);
+}
+
+#[test]
+#[should_panic]
+fn jacobi_rejects_even() {
+    jacobi(1, 10);
+}
+
+/// Covers odd prime powers via Hensel lifting, powers of two and non-unit `a`
+#[test]
+fn sqrt_mod_matches_brute_force() {
+    for n in 1..LIMIT {
+        for a in 0..n {
+            match sqrt_mod(a, n) {
+                Some(x) => assert_eq!(x * x % n, a, "sqrt({a}) mod {n} = {x}"),
+                None => assert!(!is_square(a, n), "sqrt({a}) mod {n} exists"),
+            }
+        }
+    }
+}
+
+#[test]
+fn sqrt_mod_large_powers() {
+    for (p, e) in [(2u64, 40u32), (3, 25), (5, 20), (1_000_003, 3)] {
+        let n = p.pow(e);
+        for x in [1, 3, 7, 12345, 987_654_321] {
+            let a = (x as u128 * x as u128 % n as u128) as u64;
+            let root = sqrt_mod(a, n).unwrap();
+            assert_eq!(
+                root as u128 * root as u128 % n as u128,
+                a as u128,
+                "mod {p}^{e}"
+            );
+        }
+    }
+}
+
+#[test]
+fn tonelli_shanks_and_cipolla_agree() {
+    for p in (3..LIMIT).filter(|p| is_prime(*p)) {
+        let field = PrimeField::new(p as u32).unwrap();
+        for a in 0..p {
+            let element = field.create_element(a);
+            let ts = tonelli_shanks(&element, p);
+            let c = cipolla(&element, p);
+            assert_eq!(ts.is_some(), is_square(a, p), "sqrt({a}) mod {p}");
+            assert_eq!(ts.is_some(), c.is_some(), "sqrt({a}) mod {p}");
+            if let (Some(ts), Some(c)) = (ts, c) {
+                assert_eq!(ts * ts, element);
+                assert!(ts == c || ts == -c, "sqrt({a}) mod {p}: {ts} vs {c}");
+            }
+        }
+    }
+}
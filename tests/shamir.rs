@@ -0,0 +1,75 @@
+use zk_exam::{
+    field::PrimeField,
+    params::SeededRng,
+    ring::Ring,
+    shamir::{ShamirError, reconstruct, split},
+};
+
+#[test]
+fn any_threshold_shares_recover_the_secret() {
+    let field = PrimeField::new(97).unwrap();
+    let mut rng = SeededRng::new(7);
+    for value in [0, 1, 42, 96] {
+        let secret = field.create_element(value);
+        let (shares, polynomial) = split(&secret, 3, 5, &mut rng).unwrap();
+        assert_eq!(polynomial.evaluate(&field.zero()), secret);
+        for i in 0..5 {
+            for j in i + 1..5 {
+                for k in j + 1..5 {
+                    let subset = [shares[i], shares[j], shares[k]];
+                    assert_eq!(reconstruct(&subset, 3).unwrap().0, secret);
+                }
+            }
+        }
+        assert_eq!(reconstruct(&shares, 3).unwrap().0, secret);
+    }
+}
+
+#[test]
+fn corrupted_share_is_detected() {
+    let field = PrimeField::new(97).unwrap();
+    let secret = field.create_element(42);
+    let (shares, _) = split(&secret, 3, 5, &mut SeededRng::new(1)).unwrap();
+    for corrupted in 0..shares.len() {
+        let mut tampered = shares.clone();
+        tampered[corrupted].y = tampered[corrupted].y + field.one();
+        assert_eq!(
+            reconstruct(&tampered, 3).unwrap_err(),
+            ShamirError::Inconsistent
+        );
+        // Without extra shares nothing can be checked and the secret comes out wrong
+        let subset: Vec<_> = (0..3).map(|i| tampered[(corrupted + i) % 5]).collect();
+        assert_ne!(reconstruct(&subset, 3).unwrap().0, secret);
+    }
+}
+
+#[test]
+fn invalid_parameters() {
+    let field = PrimeField::new(5).unwrap();
+    let secret = field.one();
+    let mut rng = SeededRng::new(0);
+    assert_eq!(
+        split(&secret, 0, 3, &mut rng).unwrap_err(),
+        ShamirError::InvalidThreshold {
+            threshold: 0,
+            count: 3
+        }
+    );
+    assert_eq!(
+        split(&secret, 4, 3, &mut rng).unwrap_err(),
+        ShamirError::InvalidThreshold {
+            threshold: 4,
+            count: 3
+        }
+    );
+    // Shares live at x = 1..=count, x = 5 would be zero
+    assert!(split(&secret, 2, 5, &mut rng).is_err());
+    let (shares, _) = split(&secret, 2, 4, &mut rng).unwrap();
+    assert_eq!(
+        reconstruct(&shares[..1], 2).unwrap_err(),
+        ShamirError::NotEnoughShares {
+            threshold: 2,
+            given: 1
+        }
+    );
+}
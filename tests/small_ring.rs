@@ -0,0 +1,68 @@
+use zk_exam::ring::{Ring, RingElement, RingError, SmallRing};
+
+const MAX_MODULE: u32 = 24;
+
+#[test]
+fn arithmetic_matches_integers_for_all_pairs() {
+    for module in 2..=MAX_MODULE {
+        let ring = SmallRing::new(module).unwrap();
+        let n = module as i64;
+        for a in 0..n {
+            let x = ring.try_create_element(a as u64).unwrap();
+            assert_eq!((-x).value() as i64, (-a).rem_euclid(n), "-{a} mod {n}");
+            for b in 0..n {
+                let y = ring.try_create_element(b as u64).unwrap();
+                assert_eq!((x + y).value() as i64, (a + b) % n, "{a} + {b} mod {n}");
+                assert_eq!(
+                    (x - y).value() as i64,
+                    (a - b).rem_euclid(n),
+                    "{a} - {b} mod {n}"
+                );
+                assert_eq!((x * y).value() as i64, a * b % n, "{a} * {b} mod {n}");
+                assert_eq!(x - y + y, x);
+                match x.checked_rem(y) {
+                    Ok(r) => assert_eq!(r.value() as i64, a % b, "{a} % {b} mod {n}"),
+                    Err(e) => {
+                        assert_eq!(b, 0);
+                        assert_eq!(e, RingError::DivisionByZero);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn checked_operations_reject_ring_mismatch() {
+    let x = SmallRing::new(5).unwrap().create_element(3);
+    let y = SmallRing::new(7).unwrap().create_element(3);
+    let mismatch = Err(RingError::RingMismatch { lhs: 5, rhs: 7 });
+    assert_eq!(x.checked_add(y), mismatch);
+    assert_eq!(x.checked_sub(y), mismatch);
+    assert_eq!(x.checked_mul(y), mismatch);
+    assert_eq!(x.checked_rem(y), mismatch);
+}
+
+#[test]
+#[should_panic(expected = "Ring mismatch")]
+fn operators_panic_on_ring_mismatch() {
+    let x = SmallRing::new(5).unwrap().create_element(3);
+    let y = SmallRing::new(7).unwrap().create_element(3);
+    let _ = x - y;
+}
+
+#[test]
+fn construction_keeps_values_reduced() {
+    assert_eq!(SmallRing::new(0), Err(RingError::InvalidModule(0)));
+    assert_eq!(SmallRing::new(1), Err(RingError::InvalidModule(1)));
+    let ring = SmallRing::new(6).unwrap();
+    assert_eq!(ring.create_element(13).value(), 1);
+    assert_eq!(
+        ring.try_create_element(6),
+        Err(RingError::ValueOutOfRange {
+            value: 6,
+            module: 6
+        })
+    );
+    assert_eq!(ring.zero().inverse(), None);
+}
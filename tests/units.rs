@@ -0,0 +1,64 @@
+use zk_exam::units::{
+    carmichael, check_generator, euler_totient, has_primitive_root, multiplicative_order,
+    primitive_root,
+};
+
+const LIMIT: u64 = 300;
+
+fn gcd(x: u64, y: u64) -> u64 {
+    if y == 0 { x } else { gcd(y, x % y) }
+}
+
+/// Order by repeated multiplication, `None` for non-units
+fn brute_force_order(a: u64, n: u64) -> Option<u64> {
+    if gcd(a % n, n) != 1 {
+        return None;
+    }
+    let mut power = a % n;
+    let mut k = 1;
+    while power != 1 % n {
+        power = power * a % n;
+        k += 1;
+    }
+    Some(k)
+}
+
+#[test]
+fn unit_group_functions_match_brute_force() {
+    for n in 1..LIMIT {
+        let orders: Vec<u64> = (0..n).filter_map(|a| brute_force_order(a, n)).collect();
+        assert_eq!(euler_totient(n), orders.len() as u64, "φ({n})");
+        assert_eq!(carmichael(n), *orders.iter().max().unwrap(), "λ({n})");
+        for a in 0..n + 3 {
+            assert_eq!(
+                multiplicative_order(a, n),
+                brute_force_order(a, n),
+                "ord({a}) mod {n}"
+            );
+        }
+        let phi = euler_totient(n);
+        let smallest_generator =
+            (if n <= 2 { 0 } else { 1 }..n).find(|a| brute_force_order(*a, n) == Some(phi));
+        assert_eq!(has_primitive_root(n), smallest_generator.is_some(), "{n}");
+        assert_eq!(primitive_root(n), smallest_generator, "{n}");
+    }
+}
+
+#[test]
+fn generator_checks() {
+    let check = check_generator(3, 7);
+    assert!(check.is_generator());
+    assert_eq!(check.powers, [[2, 6], [3, 2]]);
+    assert!(!check_generator(2, 7).is_generator());
+    assert!(!check_generator(4, 8).is_unit);
+    for n in 3..LIMIT {
+        for g in 2..n {
+            let is_generator = brute_force_order(g, n) == Some(euler_totient(n));
+            assert_eq!(
+                check_generator(g, n).is_generator(),
+                is_generator,
+                "{g} mod {n}"
+            );
+        }
+    }
+}